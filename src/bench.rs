@@ -0,0 +1,360 @@
+use crate::{
+    samples::duration_from_f64_nanos, Clock, DurationExtension, FormatStyle, MonotonicClock,
+    Samples, Sink, Stats,
+};
+use std::{fmt, hint::black_box, time::Duration};
+
+/// The largest number of iterations in a sample.
+const MAX_ITERATIONS: u64 = 1 << 32;
+
+/// Benchmarks a closure with the default settings of [`Bench`], prints the
+/// summary to `stdout` and returns the report.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::bench;
+///
+/// let report = bench("sum", || (1..=100_u64).sum::<u64>());
+/// assert_eq!(report.name(), "sum");
+/// ```
+pub fn bench<T>(name: impl Into<String>, f: impl FnMut() -> T) -> BenchReport {
+    Bench::new().run(name, f)
+}
+
+/// A lightweight micro-benchmark harness.
+///
+/// The closure first runs during a warmup period, which also calibrates the
+/// number of iterations so that each sample lasts about the target sample
+/// time. Then the samples are collected, and the time per iteration is
+/// summarized in a [`BenchReport`], which is written to a [`Sink`].
+///
+/// The results of the closure go through `std::hint::black_box`, so they are
+/// not optimized away; inputs can be wrapped in `black_box` too.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{Bench, Sink};
+/// use std::{hint::black_box, time::Duration};
+///
+/// let sink = Sink::buffer();
+/// let report = Bench::new()
+///     .with_warmup_time(Duration::from_millis(10))
+///     .with_sample_time(Duration::from_millis(1))
+///     .with_sample_count(10)
+///     .with_sink(sink.clone())
+///     .run("sort", || {
+///         let mut values = black_box(vec![3, 1, 2]);
+///         values.sort();
+///         values
+///     });
+///
+/// assert_eq!(report.stats().count, 10);
+/// assert!(sink.contents().unwrap().starts_with("sort: "));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bench<C = MonotonicClock> {
+    clock: C,
+    warmup_time: Duration,
+    sample_time: Duration,
+    sample_count: usize,
+    sink: Sink,
+}
+
+impl Bench {
+    /// Creates a harness with a warmup of 100 ms and 30 samples of about 10 ms,
+    /// that reads time from the monotonic clock.
+    pub fn new() -> Self {
+        Self::with_clock(MonotonicClock::default())
+    }
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> Bench<C> {
+    /// Creates a harness that reads time from the given clock.
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            warmup_time: Duration::from_millis(100),
+            sample_time: Duration::from_millis(10),
+            sample_count: 30,
+            sink: Sink::default(),
+        }
+    }
+
+    /// Sets how long the closure runs before the samples are collected.
+    pub fn with_warmup_time(mut self, warmup_time: Duration) -> Self {
+        self.warmup_time = warmup_time;
+        self
+    }
+
+    /// Sets the target duration of each sample.
+    pub fn with_sample_time(mut self, sample_time: Duration) -> Self {
+        self.sample_time = sample_time;
+        self
+    }
+
+    /// Sets the number of samples (at least 1).
+    pub fn with_sample_count(mut self, sample_count: usize) -> Self {
+        self.sample_count = sample_count.max(1);
+        self
+    }
+
+    /// Sets the destination of the summary.
+    pub fn with_sink(mut self, sink: Sink) -> Self {
+        self.sink = sink;
+        self
+    }
+
+    pub(crate) fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    pub(crate) fn sink(&self) -> &Sink {
+        &self.sink
+    }
+
+    /// Benchmarks the closure, writes the summary to the sink and returns the report.
+    pub fn run<T>(&self, name: impl Into<String>, mut f: impl FnMut() -> T) -> BenchReport {
+        let report: BenchReport = self.measure(name.into(), &mut f);
+        // Errors cannot be reported from here, so they are ignored.
+        let _ = self.sink.write_line(&report.format_report());
+        report
+    }
+
+    /// Warms up, calibrates and collects the samples, without writing the summary.
+    pub(crate) fn measure<T>(&self, name: String, f: &mut impl FnMut() -> T) -> BenchReport {
+        let iterations: u64 = self.calibrate(f);
+        let mut samples = Samples::new();
+        let mut total = Duration::ZERO;
+
+        for _ in 0..self.sample_count {
+            let elapsed: Duration = self.run_batch(f, iterations);
+            total += elapsed;
+            samples.push(per_iteration(elapsed, iterations));
+        }
+
+        BenchReport::new(name, iterations, samples, total)
+    }
+
+    /// Runs the closure during the warmup time, doubling the number of
+    /// iterations until a batch lasts the sample time, and returns the number
+    /// of iterations that fits in a sample.
+    pub(crate) fn calibrate<T>(&self, f: &mut impl FnMut() -> T) -> u64 {
+        let start: Duration = self.clock.now();
+        let mut iterations: u64 = 1;
+
+        loop {
+            let elapsed: Duration = self.run_batch(f, iterations);
+            let warm: bool = self.clock.now().saturating_sub(start) >= self.warmup_time;
+
+            if elapsed < self.sample_time && iterations < MAX_ITERATIONS {
+                if !warm {
+                    iterations *= 2;
+                    continue;
+                }
+            } else if !warm {
+                continue;
+            }
+
+            if elapsed.is_zero() {
+                return iterations;
+            }
+            let scale: f64 = self.sample_time.as_secs_f64() / elapsed.as_secs_f64();
+            return ((iterations as f64 * scale).round() as u64).clamp(1, MAX_ITERATIONS);
+        }
+    }
+
+    /// Runs the closure the given number of times and returns the elapsed time.
+    pub(crate) fn run_batch<T>(&self, f: &mut impl FnMut() -> T, iterations: u64) -> Duration {
+        let start: Duration = self.clock.now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        self.clock.now().saturating_sub(start)
+    }
+}
+
+/// Divides the duration of a batch by its number of iterations.
+pub(crate) fn per_iteration(elapsed: Duration, iterations: u64) -> Duration {
+    duration_from_f64_nanos(elapsed.as_nanos() as f64 / iterations as f64)
+}
+
+/// The result of a benchmark, created by [`Bench::run`] or [`bench()`].
+///
+/// Each sample holds the mean time of one iteration within a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    name: String,
+    iterations: u64,
+    samples: Samples,
+    stats: Stats,
+    total: Duration,
+}
+
+impl BenchReport {
+    pub(crate) fn new(name: String, iterations: u64, samples: Samples, total: Duration) -> Self {
+        let stats: Stats = samples.stats().expect("at least one sample");
+        Self {
+            name,
+            iterations,
+            samples,
+            stats,
+            total,
+        }
+    }
+
+    /// Gets the name of the benchmark.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the number of iterations in each sample.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Gets the time per iteration of each sample.
+    pub fn samples(&self) -> &Samples {
+        &self.samples
+    }
+
+    /// Gets the statistics of the time per iteration.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Gets the number of iterations per second, over all the samples.
+    pub fn throughput(&self) -> f64 {
+        let iterations: f64 = self.iterations as f64 * self.stats.count as f64;
+        iterations / self.total.as_secs_f64()
+    }
+
+    /// Formats the summary, such as
+    /// "sum: 1.25 microseconds ± 20 nanoseconds per iteration (800.00K iterations/s, 30 samples × 8000 iterations)".
+    pub fn format_report(&self) -> String {
+        let format =
+            |duration: Duration| duration.get_exact_time().format_with(FormatStyle::Natural);
+
+        format!(
+            "{}: {} ± {} per iteration ({} iterations/s, {} samples × {} iterations)",
+            self.name,
+            format(self.stats.mean),
+            format(self.stats.std_dev),
+            format_rate(self.throughput()),
+            self.stats.count,
+            self.iterations
+        )
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_report())
+    }
+}
+
+/// Formats a rate with a metric prefix, such as "812.35K".
+pub(crate) fn format_rate(rate: f64) -> String {
+    const PREFIXES: [(f64, &str); 3] = [(1e9, "G"), (1e6, "M"), (1e3, "K")];
+
+    match PREFIXES.iter().find(|(scale, _)| rate >= *scale) {
+        Some((scale, prefix)) if rate.is_finite() => format!("{:.2}{prefix}", rate / scale),
+        _ => format!("{rate:.2}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+
+    fn harness(clock: &ManualClock, sink: &Sink) -> Bench<ManualClock> {
+        Bench::with_clock(clock.clone())
+            .with_warmup_time(Duration::from_millis(1))
+            .with_sample_time(Duration::from_micros(100))
+            .with_sample_count(5)
+            .with_sink(sink.clone())
+    }
+
+    #[test]
+    fn calibrates_iterations() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+
+        // Batches of 1, 2, 4, ..., 512 iterations warm up for 1023 µs.
+        let mut calls: u64 = 0;
+        let report = harness(&clock, &sink).run("step", || {
+            calls += 1;
+            clock.advance(Duration::from_micros(1));
+        });
+
+        assert_eq!(report.iterations(), 100);
+        assert_eq!(calls, 1023 + 5 * 100);
+        assert_eq!(report.samples().len(), 5);
+        assert_eq!(report.stats().mean, Duration::from_micros(1));
+        assert_eq!(report.stats().std_dev, Duration::ZERO);
+        assert_eq!(report.throughput(), 1e6);
+    }
+
+    #[test]
+    fn slow_closures_run_once_per_sample() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+
+        let report = harness(&clock, &sink).run("slow", || clock.advance(Duration::from_millis(2)));
+
+        assert_eq!(report.iterations(), 1);
+        assert_eq!(report.stats().mean, Duration::from_millis(2));
+        assert_eq!(report.throughput(), 500.0);
+    }
+
+    #[test]
+    fn writes_summary() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+
+        let mut step: u64 = 0;
+        let report = harness(&clock, &sink).run("sum", || {
+            step += 1;
+            // Alternate between 1 µs and 1.5 µs per iteration.
+            clock.advance(Duration::from_nanos(1000 + 500 * (step % 2)));
+        });
+
+        let summary = report.format_report();
+        assert_eq!(sink.contents().unwrap(), format!("{summary}\n"));
+        assert_eq!(report.to_string(), summary);
+        assert!(
+            summary.starts_with("sum: 1.25 microseconds ± 0 seconds per iteration (800.00K iterations/s, 5 samples × "),
+            "{summary}"
+        );
+    }
+
+    #[test]
+    fn rates() {
+        assert_eq!(format_rate(12.5), "12.50");
+        assert_eq!(format_rate(812_345.0), "812.35K");
+        assert_eq!(format_rate(2.5e6), "2.50M");
+        assert_eq!(format_rate(3e9), "3.00G");
+        assert_eq!(format_rate(f64::INFINITY), "inf");
+    }
+
+    #[test]
+    fn monotonic_clock() {
+        let report = Bench::new()
+            .with_warmup_time(Duration::ZERO)
+            .with_sample_time(Duration::from_micros(50))
+            .with_sample_count(3)
+            .with_sink(Sink::buffer())
+            .run("noop", || black_box(1) + 1);
+
+        assert_eq!(report.stats().count, 3);
+        assert!(report.iterations() >= 1);
+    }
+}
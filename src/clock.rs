@@ -0,0 +1,111 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A source of monotonic time readings for [`ExecutionTime`](crate::ExecutionTime).
+///
+/// Readings are measured from an arbitrary, clock-specific origin: only the
+/// difference between two readings of the same clock is meaningful.
+pub trait Clock {
+    /// Returns the current reading of the clock.
+    fn now(&self) -> Duration;
+}
+
+/// The default clock, backed by `std::time::Instant`.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    origin: Instant,
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests.
+///
+/// Clones share the same reading, so a test can keep a handle to the clock
+/// given to an `ExecutionTime` and advance it explicitly.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{ExecutionTime, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let timer = ExecutionTime::start_with_clock(clock.clone());
+///
+/// clock.advance(Duration::from_millis(1500));
+/// assert_eq!(timer.get_duration(), Duration::from_millis(1500));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a new clock whose reading is zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward by the given amount.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Sets the reading of the clock.
+    pub fn set(&self, now: Duration) {
+        *self.lock() = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Duration> {
+        // A poisoned lock still holds a valid `Duration`.
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monotonic_clock_advances() {
+        let clock = MonotonicClock::default();
+        let first = clock.now();
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(clock.now() > first);
+    }
+
+    #[test]
+    fn manual_clock_is_shared_between_clones() {
+        let clock = ManualClock::new();
+        let handle = clock.clone();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        handle.advance(Duration::from_secs(2));
+        assert_eq!(clock.now(), Duration::from_secs(2));
+
+        handle.set(Duration::from_millis(500));
+        assert_eq!(clock.now(), Duration::from_millis(500));
+    }
+}
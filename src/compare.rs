@@ -0,0 +1,660 @@
+use crate::{exact_time::trim_fraction, Bench, BenchReport, Clock, MonotonicClock, Samples};
+use std::{fmt, hint::black_box, time::Duration};
+
+/// A benchmarked closure, with its result discarded through `black_box`.
+type Candidate<'a> = (String, Box<dyn FnMut() + 'a>);
+
+/// Benchmarks two or more closures and compares them with a statistical test.
+///
+/// Every closure is warmed up and calibrated as in [`Bench`], then the
+/// samples are collected interleaved (one batch of each closure in turn, in
+/// a rotating order), so that a change of load affects all of them alike.
+///
+/// The first closure is the baseline. Each of the others is compared to it
+/// with Welch's t-test on the time per iteration, and the report gives the
+/// speedup with its confidence interval and a verdict such as
+/// "new is 1.34x faster than old (95% CI: 1.30x to 1.38x), p < 0.01".
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{Bench, Comparison, Sink};
+/// use std::{hint::black_box, time::Duration};
+///
+/// let bench = Bench::new()
+///     .with_warmup_time(Duration::from_millis(10))
+///     .with_sample_time(Duration::from_millis(1))
+///     .with_sample_count(10)
+///     .with_sink(Sink::buffer());
+///
+/// let report = Comparison::with_bench(bench)
+///     .with_candidate("loop", || (1..=black_box(1000_u64)).sum::<u64>())
+///     .with_candidate("formula", || {
+///         let n = black_box(1000_u64);
+///         n * (n + 1) / 2
+///     })
+///     .run();
+///
+/// assert_eq!(report.reports().len(), 2);
+/// assert_eq!(report.speedups()[0].candidate(), "formula");
+/// ```
+pub struct Comparison<'a, C = MonotonicClock> {
+    bench: Bench<C>,
+    candidates: Vec<Candidate<'a>>,
+    confidence: f64,
+}
+
+impl<'a> Comparison<'a> {
+    /// Creates a comparison with the default settings of [`Bench`].
+    pub fn new() -> Self {
+        Self::with_bench(Bench::new())
+    }
+}
+
+impl Default for Comparison<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, C: Clock> Comparison<'a, C> {
+    /// Creates a comparison that runs with the settings of the given harness,
+    /// and writes the summary to its sink.
+    pub fn with_bench(bench: Bench<C>) -> Self {
+        Self {
+            bench,
+            candidates: Vec::new(),
+            confidence: 0.95,
+        }
+    }
+
+    /// Adds a closure to compare. The first one is the baseline.
+    pub fn with_candidate<T>(
+        mut self,
+        name: impl Into<String>,
+        mut f: impl FnMut() -> T + 'a,
+    ) -> Self {
+        let f = move || {
+            black_box(f());
+        };
+        self.candidates.push((name.into(), Box::new(f)));
+        self
+    }
+
+    /// Sets the confidence level of the intervals (by default, 0.95). A
+    /// difference is significant when its p-value is below `1 - confidence`.
+    pub fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = confidence.clamp(0.5, 0.9999);
+        self
+    }
+
+    /// Benchmarks the closures, writes the summary to the sink and returns the report.
+    pub fn run(mut self) -> ComparisonReport {
+        let bench: &Bench<C> = &self.bench;
+        let count: usize = self.candidates.len();
+
+        let iterations: Vec<u64> = self
+            .candidates
+            .iter_mut()
+            .map(|(_, f)| bench.calibrate(f))
+            .collect();
+
+        let mut samples: Vec<Samples> = vec![Samples::new(); count];
+        let mut totals: Vec<Duration> = vec![Duration::ZERO; count];
+
+        for round in 0..bench.sample_count() {
+            for offset in 0..count {
+                let index: usize = (round + offset) % count;
+                let elapsed: Duration =
+                    bench.run_batch(&mut self.candidates[index].1, iterations[index]);
+                totals[index] += elapsed;
+                samples[index].push(crate::bench::per_iteration(elapsed, iterations[index]));
+            }
+        }
+
+        let reports: Vec<BenchReport> = self
+            .candidates
+            .into_iter()
+            .zip(iterations)
+            .zip(samples.into_iter().zip(totals))
+            .map(|(((name, _), iterations), (samples, total))| {
+                BenchReport::new(name, iterations, samples, total)
+            })
+            .collect();
+
+        let speedups: Vec<Speedup> = match reports.split_first() {
+            Some((baseline, others)) => others
+                .iter()
+                .map(|candidate| Speedup::new(baseline, candidate, self.confidence))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let report = ComparisonReport { reports, speedups };
+        // Errors cannot be reported from here, so they are ignored.
+        let _ = bench.sink().write_line(&report.format_report());
+        report
+    }
+}
+
+/// The result of a [`Comparison`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    reports: Vec<BenchReport>,
+    speedups: Vec<Speedup>,
+}
+
+impl ComparisonReport {
+    /// Gets the benchmark of each closure, in the order they were added.
+    pub fn reports(&self) -> &[BenchReport] {
+        &self.reports
+    }
+
+    /// Gets the comparison of each closure to the baseline (the first one).
+    pub fn speedups(&self) -> &[Speedup] {
+        &self.speedups
+    }
+
+    /// Formats the benchmark of each closure, then the verdict of each comparison.
+    pub fn format_report(&self) -> String {
+        self.reports
+            .iter()
+            .map(BenchReport::format_report)
+            .chain(self.speedups.iter().map(Speedup::verdict))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for ComparisonReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_report())
+    }
+}
+
+/// The speedup of a closure relative to the baseline of a [`Comparison`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Speedup {
+    baseline: String,
+    candidate: String,
+    ratio: f64,
+    low: f64,
+    high: f64,
+    p_value: f64,
+    confidence: f64,
+    /// Whether both closures have at least 2 samples.
+    enough_samples: bool,
+}
+
+impl Speedup {
+    /// Compares the time per iteration of the candidate to the baseline.
+    fn new(baseline: &BenchReport, candidate: &BenchReport, confidence: f64) -> Self {
+        let a: Moments = Moments::of(baseline.samples());
+        let b: Moments = Moments::of(candidate.samples());
+        let test: WelchTest = WelchTest::new(&a, &b);
+
+        // The ratio of the means, with its standard error by the delta method.
+        let ratio: f64 = a.mean / b.mean;
+        let relative_error: f64 = (a.variance / (a.count * a.mean.powi(2))
+            + b.variance / (b.count * b.mean.powi(2)))
+        .sqrt();
+        let enough_samples: bool = !test.df.is_nan();
+        let (low, high) = if !enough_samples {
+            (0.0, f64::INFINITY)
+        } else {
+            let margin: f64 = t_critical(confidence, test.df) * ratio * relative_error;
+            ((ratio - margin).max(0.0), ratio + margin)
+        };
+
+        Self {
+            baseline: baseline.name().to_string(),
+            candidate: candidate.name().to_string(),
+            ratio,
+            low,
+            high,
+            p_value: test.p_value,
+            confidence,
+            enough_samples,
+        }
+    }
+
+    /// Gets the name of the baseline.
+    pub fn baseline(&self) -> &str {
+        &self.baseline
+    }
+
+    /// Gets the name of the compared closure.
+    pub fn candidate(&self) -> &str {
+        &self.candidate
+    }
+
+    /// Gets the speedup: the mean time of the baseline divided by the mean
+    /// time of the candidate. Above 1, the candidate is faster.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Gets the confidence interval of the speedup, unbounded when either
+    /// closure has fewer than 2 samples.
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        (self.low, self.high)
+    }
+
+    /// Gets the two-sided p-value of Welch's t-test.
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    /// Returns `true` if the p-value is below `1 - confidence`.
+    pub fn is_significant(&self) -> bool {
+        self.p_value < 1.0 - self.confidence
+    }
+
+    /// Formats the verdict, such as "new is 1.34x faster than old (95% CI: 1.30x to 1.38x), p < 0.01",
+    /// "No significant difference between old and new (p = 0.42)",
+    /// "Not enough samples to compare old and new" with fewer than 2 samples, or
+    /// "Cannot compute a speedup of new over old: the mean of new is zero".
+    pub fn verdict(&self) -> String {
+        let (baseline, candidate) = (&self.baseline, &self.candidate);
+        let p: String = format_p_value(self.p_value);
+
+        if !self.enough_samples {
+            return format!("Not enough samples to compare {baseline} and {candidate}");
+        }
+
+        // A zero mean makes the ratio infinite, zero or undefined.
+        if !self.ratio.is_finite() || self.ratio == 0.0 {
+            let zero: &str = if self.ratio == 0.0 {
+                baseline
+            } else {
+                candidate
+            };
+            return format!(
+                "Cannot compute a speedup of {candidate} over {baseline}: the mean of {zero} is zero"
+            );
+        }
+
+        if !self.is_significant() {
+            return format!("No significant difference between {baseline} and {candidate} ({p})");
+        }
+
+        // A slowdown is reported as its inverse, so that the factor is above 1.
+        let (direction, factor, low, high) = if self.ratio >= 1.0 {
+            ("faster", self.ratio, self.low, self.high)
+        } else {
+            (
+                "slower",
+                self.ratio.recip(),
+                self.high.recip(),
+                self.low.recip(),
+            )
+        };
+
+        // Rounded to hundredths of a percent, so that 0.57 is "57" and not "56.99999999999999".
+        let level: String = trim_fraction(format!("{:.2}", self.confidence * 100.0));
+
+        format!(
+            "{candidate} is {factor:.2}x {direction} than {baseline} ({level}% CI: {low:.2}x to {high:.2}x), {p}"
+        )
+    }
+}
+
+/// Formats a p-value, with thresholds for the small ones.
+fn format_p_value(p_value: f64) -> String {
+    match [0.001, 0.01]
+        .into_iter()
+        .find(|threshold| p_value < *threshold)
+    {
+        Some(threshold) => format!("p < {threshold}"),
+        None => format!("p = {p_value:.2}"),
+    }
+}
+
+/// The sample mean and variance of the time per iteration, in nanoseconds.
+struct Moments {
+    count: f64,
+    mean: f64,
+    variance: f64,
+}
+
+impl Moments {
+    fn of(samples: &Samples) -> Self {
+        let (mean, variance) = samples.mean_and_variance().unwrap_or((f64::NAN, 0.0));
+
+        Self {
+            count: samples.len() as f64,
+            mean,
+            variance,
+        }
+    }
+}
+
+/// Welch's t-test for the difference of two means with unequal variances.
+struct WelchTest {
+    df: f64,
+    p_value: f64,
+}
+
+impl WelchTest {
+    fn new(a: &Moments, b: &Moments) -> Self {
+        let (va, vb) = (a.variance / a.count, b.variance / b.count);
+        let error: f64 = (va + vb).sqrt();
+
+        if a.count < 2.0 || b.count < 2.0 {
+            // A single sample gives no estimate of the variance: no conclusion.
+            return Self {
+                df: f64::NAN,
+                p_value: 1.0,
+            };
+        }
+
+        if error == 0.0 {
+            // Without variance, any difference is certain.
+            let p_value: f64 = if a.mean == b.mean { 1.0 } else { 0.0 };
+            return Self {
+                df: f64::INFINITY,
+                p_value,
+            };
+        }
+
+        let t: f64 = (a.mean - b.mean) / error;
+        let df: f64 =
+            (va + vb).powi(2) / (va.powi(2) / (a.count - 1.0) + vb.powi(2) / (b.count - 1.0));
+
+        Self {
+            df,
+            p_value: student_t_p_value(t, df),
+        }
+    }
+}
+
+/// Gets the two-sided p-value of Student's t distribution.
+fn student_t_p_value(t: f64, df: f64) -> f64 {
+    if !df.is_finite() {
+        // The normal limit, through the same function.
+        return student_t_p_value(t, 1e7);
+    }
+    incomplete_beta(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// Gets the critical value of Student's t distribution for a two-sided
+/// confidence interval, by bisection.
+fn t_critical(confidence: f64, df: f64) -> f64 {
+    let alpha: f64 = 1.0 - confidence;
+    let (mut low, mut high) = (0.0, 1.0);
+
+    // Grow the bracket until it holds the value: with few degrees of freedom
+    // and a high confidence, it reaches the thousands.
+    while student_t_p_value(high, df) > alpha && high < f64::MAX / 2.0 {
+        low = high;
+        high *= 2.0;
+    }
+
+    for _ in 0..100 {
+        let middle: f64 = (low + high) / 2.0;
+        if student_t_p_value(middle, df) > alpha {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// The regularized incomplete beta function I_x(a, b).
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front: f64 =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+    // The continued fraction converges quickly on this side of the mean.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function (modified Lentz).
+fn beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let clamp = |value: f64| if value.abs() < TINY { TINY } else { value };
+
+    let mut c: f64 = 1.0;
+    let mut d: f64 = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut result: f64 = d;
+
+    for m in 1..=300 {
+        let m: f64 = m as f64;
+        let even: f64 = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        let odd: f64 = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+
+        for coefficient in [even, odd] {
+            d = 1.0 / clamp(1.0 + coefficient * d);
+            c = clamp(1.0 + coefficient / c);
+            result *= d * c;
+        }
+
+        if (d * c - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    result
+}
+
+/// The natural logarithm of the gamma function (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+
+    let tmp: f64 = x + 5.5;
+    let series: f64 = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .fold(1.000_000_000_190_015, |sum, (i, c)| {
+            sum + c / (x + 1.0 + i as f64)
+        });
+
+    (2.506_628_274_631_000_5 * series / x).ln() + (x + 0.5) * tmp.ln() - tmp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ManualClock, Sink};
+
+    fn close(actual: f64, expected: f64, tolerance: f64) -> bool {
+        (actual - expected).abs() < tolerance
+    }
+
+    #[test]
+    fn distributions() {
+        assert!(close(incomplete_beta(1.0, 1.0, 0.5), 0.5, 1e-12));
+        assert!(close(incomplete_beta(2.0, 3.0, 0.4), 0.5248, 1e-10));
+        assert!(close(ln_gamma(5.0), 24_f64.ln(), 1e-10));
+
+        assert!(close(student_t_p_value(0.0, 10.0), 1.0, 1e-12));
+        assert!(close(student_t_p_value(2.0, 5.0), 0.101_939, 1e-5));
+        assert!(close(t_critical(0.95, 10.0), 2.228_139, 1e-5));
+        assert!(close(t_critical(0.95, 1.0), 12.706_205, 1e-4));
+        assert!(close(t_critical(0.9999, 1.0), 6366.197, 1e-2));
+        assert!(close(t_critical(0.95, f64::INFINITY), 1.959_964, 1e-4));
+    }
+
+    #[test]
+    fn welch_t_test() {
+        let moments = |values: &[f64]| {
+            let samples: Samples = values
+                .iter()
+                .map(|x| Duration::from_nanos((x * 10.0).round() as u64))
+                .collect();
+            Moments::of(&samples)
+        };
+
+        let a = moments(&[
+            27.5, 21.0, 19.0, 23.6, 17.0, 17.9, 16.9, 20.1, 21.9, 22.6, 23.1, 19.6, 19.0, 21.7,
+            21.4,
+        ]);
+        let b = moments(&[
+            27.1, 22.0, 20.8, 23.4, 23.4, 23.5, 25.8, 22.0, 24.8, 20.2, 21.9, 22.1, 22.9, 20.5,
+            24.4,
+        ]);
+        let test = WelchTest::new(&a, &b);
+
+        assert!(close(test.df, 24.99, 0.01), "{}", test.df);
+        assert!(close(test.p_value, 0.021, 0.001), "{}", test.p_value);
+    }
+
+    fn harness(clock: &ManualClock, sink: &Sink) -> Bench<ManualClock> {
+        Bench::with_clock(clock.clone())
+            .with_warmup_time(Duration::from_millis(1))
+            .with_sample_time(Duration::from_micros(100))
+            .with_sample_count(10)
+            .with_sink(sink.clone())
+    }
+
+    #[test]
+    fn faster_and_slower() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+
+        let report = Comparison::with_bench(harness(&clock, &sink))
+            .with_candidate("old", || clock.advance(Duration::from_micros(2)))
+            .with_candidate("new", || clock.advance(Duration::from_micros(1)))
+            .with_candidate("naive", || clock.advance(Duration::from_micros(5)))
+            .run();
+
+        let [faster, slower] = report.speedups() else {
+            panic!("two comparisons expected");
+        };
+        assert_eq!(faster.ratio(), 2.0);
+        assert_eq!(faster.p_value(), 0.0);
+        assert_eq!(
+            faster.verdict(),
+            "new is 2.00x faster than old (95% CI: 2.00x to 2.00x), p < 0.001"
+        );
+        assert_eq!(
+            slower.verdict(),
+            "naive is 2.50x slower than old (95% CI: 2.50x to 2.50x), p < 0.001"
+        );
+
+        let contents = sink.contents().unwrap();
+        assert_eq!(contents, format!("{report}\n"));
+        assert_eq!(contents.lines().count(), 5);
+        assert!(contents.starts_with("old: 2 microseconds ± 0 seconds per iteration"));
+
+        // The confidence level is shown without floating point noise.
+        let report = Comparison::with_bench(harness(&clock, &Sink::buffer()))
+            .with_confidence(0.57)
+            .with_candidate("old", || clock.advance(Duration::from_micros(2)))
+            .with_candidate("new", || clock.advance(Duration::from_micros(1)))
+            .run();
+        assert_eq!(
+            report.speedups()[0].verdict(),
+            "new is 2.00x faster than old (57% CI: 2.00x to 2.00x), p < 0.001"
+        );
+    }
+
+    #[test]
+    fn interleaved_and_noisy() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+        let order = std::cell::RefCell::new(String::new());
+
+        // A fluctuating load, shared by both closures.
+        let load = std::cell::Cell::new(0_u64);
+        let step = |name: char, nanos: u64| {
+            order.borrow_mut().push(name);
+            load.set(load.get() + 1);
+            clock.advance(Duration::from_nanos(nanos + load.get() % 7));
+        };
+
+        // One iteration per sample, so that the noise is not averaged away.
+        let bench = harness(&clock, &sink)
+            .with_sample_time(Duration::from_nanos(1))
+            .with_sample_count(20);
+        let report = Comparison::with_bench(bench)
+            .with_confidence(0.99)
+            .with_candidate("a", || step('a', 1000))
+            .with_candidate("b", || step('b', 1000))
+            .run();
+
+        let speedup = &report.speedups()[0];
+        assert!(!speedup.is_significant(), "{}", speedup.verdict());
+        assert!(speedup
+            .verdict()
+            .starts_with("No significant difference between a and b (p = "));
+
+        let (low, high) = speedup.confidence_interval();
+        assert!(low < 1.0 && 1.0 < high, "{low} {high}");
+
+        // After the warmup, the batches alternate in a rotating order.
+        assert_eq!(report.reports()[0].iterations(), 1);
+        let order = order.borrow();
+        assert!(order.ends_with("abbaabba"), "{order}");
+    }
+
+    #[test]
+    fn single_sample() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+
+        let report = Comparison::with_bench(harness(&clock, &sink).with_sample_count(1))
+            .with_candidate("old", || clock.advance(Duration::from_nanos(1001)))
+            .with_candidate("new", || clock.advance(Duration::from_nanos(1000)))
+            .run();
+
+        let speedup = &report.speedups()[0];
+        assert_eq!(speedup.p_value(), 1.0);
+        assert!(!speedup.is_significant());
+        assert_eq!(speedup.confidence_interval(), (0.0, f64::INFINITY));
+        assert_eq!(
+            speedup.verdict(),
+            "Not enough samples to compare old and new"
+        );
+    }
+
+    #[test]
+    fn zero_mean() {
+        let report = |name: &str, nanos: &[u64]| {
+            let samples: Samples = nanos.iter().copied().map(Duration::from_nanos).collect();
+            BenchReport::new(name.to_string(), 1, samples, Duration::from_micros(1))
+        };
+        let old = report("old", &[1000, 1010, 990, 1005, 995]);
+        let new = report("new", &[0, 0, 0, 0, 0]);
+
+        let speedup = Speedup::new(&old, &new, 0.95);
+        assert!(speedup.is_significant());
+        assert_eq!(speedup.ratio(), f64::INFINITY);
+        assert_eq!(
+            speedup.verdict(),
+            "Cannot compute a speedup of new over old: the mean of new is zero"
+        );
+
+        let speedup = Speedup::new(&new, &old, 0.95);
+        assert_eq!(speedup.ratio(), 0.0);
+        assert_eq!(
+            speedup.verdict(),
+            "Cannot compute a speedup of old over new: the mean of new is zero"
+        );
+    }
+
+    #[test]
+    fn p_values() {
+        assert_eq!(format_p_value(0.0004), "p < 0.001");
+        assert_eq!(format_p_value(0.004), "p < 0.01");
+        assert_eq!(format_p_value(0.0312), "p = 0.03");
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{DurationExtension, ExecutionTime};
+use std::{io, time::Duration};
+
+/// Clock ticks per second used by the kernel to report CPU times (`USER_HZ`).
+///
+/// Linux exposes the times in `/proc` in units of `USER_HZ`, which is 100 on
+/// all mainstream architectures.
+const CLOCK_TICKS_PER_SECOND: u64 = 100;
+
+/// CPU time consumed in user mode and in kernel (system) mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuTime {
+    /// The time spent running the program's own code.
+    pub user: Duration,
+    /// The time spent in the kernel on behalf of the program, such as in system calls.
+    pub system: Duration,
+}
+
+impl CpuTime {
+    /// Gets the CPU time consumed so far by the current process.
+    ///
+    /// Only supported on Linux, where it is read from `/proc/self/stat`.
+    pub fn process() -> io::Result<Self> {
+        Self::read("/proc/self/stat")
+    }
+
+    /// Gets the CPU time consumed so far by the current thread.
+    ///
+    /// Only supported on Linux, where it is read from `/proc/thread-self/stat`.
+    pub fn thread() -> io::Result<Self> {
+        Self::read("/proc/thread-self/stat")
+    }
+
+    /// Gets the sum of the user and system CPU time.
+    pub fn total(&self) -> Duration {
+        self.user + self.system
+    }
+
+    /// Gets the CPU time consumed since an earlier reading.
+    pub fn saturating_sub(self, earlier: Self) -> Self {
+        Self {
+            user: self.user.saturating_sub(earlier.user),
+            system: self.system.saturating_sub(earlier.system),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn read(path: &str) -> io::Result<Self> {
+        Self::parse_stat(&std::fs::read_to_string(path)?)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read(_path: &str) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "CPU time measurement is only supported on Linux",
+        ))
+    }
+
+    /// Parses the contents of a `/proc/<pid>/stat` file.
+    ///
+    /// The second field (the command name) is enclosed in parentheses and may
+    /// itself contain spaces and parentheses, so the fields are counted from
+    /// the last `)`. The user and system times are fields 14 and 15.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    fn parse_stat(contents: &str) -> io::Result<Self> {
+        let invalid =
+            |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+
+        let (_, fields) = contents
+            .rsplit_once(')')
+            .ok_or_else(|| invalid("missing command name in stat file"))?;
+
+        // Fields after the command name start at field 3 (the process state).
+        let mut fields = fields.split_whitespace().skip(14 - 3);
+
+        let mut next_ticks = |name: &str| -> io::Result<Duration> {
+            let ticks: u64 = fields
+                .next()
+                .ok_or_else(|| invalid(&format!("missing {name} field in stat file")))?
+                .parse()
+                .map_err(|_| invalid(&format!("invalid {name} field in stat file")))?;
+            Ok(ticks_to_duration(ticks))
+        };
+
+        let user = next_ticks("utime")?;
+        let system = next_ticks("stime")?;
+
+        Ok(Self { user, system })
+    }
+}
+
+/// Converts clock ticks into a `Duration`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn ticks_to_duration(ticks: u64) -> Duration {
+    let seconds = ticks / CLOCK_TICKS_PER_SECOND;
+    let nanos = (ticks % CLOCK_TICKS_PER_SECOND) * (1_000_000_000 / CLOCK_TICKS_PER_SECOND);
+    Duration::new(seconds, nanos as u32)
+}
+
+/// Measures wall time together with process and thread CPU time.
+///
+/// ### Examples
+///
+/// ```no_run
+/// use execution_time::CpuTimer;
+///
+/// let timer = CpuTimer::start()?;
+/// // ... your code here ...
+/// println!("{}", timer.get_usage()?.format_report());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CpuTimer {
+    wall: ExecutionTime,
+    process: CpuTime,
+    thread: CpuTime,
+}
+
+impl CpuTimer {
+    /// Starts measuring wall time and the CPU time of the process and of the
+    /// current thread.
+    pub fn start() -> io::Result<Self> {
+        Ok(Self {
+            process: CpuTime::process()?,
+            thread: CpuTime::thread()?,
+            wall: ExecutionTime::start(),
+        })
+    }
+
+    /// Gets the wall and CPU time consumed since the timer was started.
+    ///
+    /// The thread CPU time refers to the thread that started the timer only
+    /// if this method is called from that same thread.
+    pub fn get_usage(&self) -> io::Result<CpuUsage> {
+        Ok(CpuUsage {
+            wall: self.wall.get_duration(),
+            process: CpuTime::process()?.saturating_sub(self.process),
+            thread: CpuTime::thread()?.saturating_sub(self.thread),
+        })
+    }
+}
+
+/// Wall and CPU time consumed during a measurement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuUsage {
+    /// The elapsed real time.
+    pub wall: Duration,
+    /// The CPU time consumed by all the threads of the process.
+    pub process: CpuTime,
+    /// The CPU time consumed by the current thread.
+    pub thread: CpuTime,
+}
+
+impl CpuUsage {
+    /// Gets the process CPU time as a percentage of the wall time.
+    ///
+    /// Values above 100% mean that several threads were running in parallel.
+    pub fn utilization(&self) -> f64 {
+        percentage(self.process.total(), self.wall)
+    }
+
+    /// Gets the thread CPU time as a percentage of the wall time.
+    pub fn thread_utilization(&self) -> f64 {
+        percentage(self.thread.total(), self.wall)
+    }
+
+    /// Formats the wall, user and system times and the CPU utilization.
+    pub fn format_report(&self) -> String {
+        let format = |duration: Duration| duration.get_time().format_time();
+
+        [
+            format!("Wall time: {}", format(self.wall)),
+            format!(
+                "User time: {} (thread: {})",
+                format(self.process.user),
+                format(self.thread.user)
+            ),
+            format!(
+                "System time: {} (thread: {})",
+                format(self.process.system),
+                format(self.thread.system)
+            ),
+            format!(
+                "CPU utilization: {:.2}% (thread: {:.2}%)",
+                self.utilization(),
+                self.thread_utilization()
+            ),
+        ]
+        .join("\n")
+    }
+}
+
+fn percentage(part: Duration, whole: Duration) -> f64 {
+    if whole.is_zero() {
+        return 0.0;
+    }
+    100.0 * part.as_secs_f64() / whole.as_secs_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stat_line() {
+        // The command name contains spaces and parentheses.
+        let line = "3872 (my (odd) cmd) R 3868 3872 3868 0 -1 4194304 79 0 0 0 \
+                    1234 56 0 0 20 0 1 0 77079 2703360 287";
+
+        let cpu_time = CpuTime::parse_stat(line).unwrap();
+
+        assert_eq!(cpu_time.user, Duration::from_millis(12_340));
+        assert_eq!(cpu_time.system, Duration::from_millis(560));
+        assert_eq!(cpu_time.total(), Duration::from_millis(12_900));
+    }
+
+    #[test]
+    fn parse_invalid_stat_line() {
+        assert!(CpuTime::parse_stat("").is_err());
+        assert!(CpuTime::parse_stat("1 (cmd) R 1 2 3").is_err());
+        assert!(CpuTime::parse_stat("1 (cmd) R 1 1 1 0 -1 0 0 0 0 0 x 0").is_err());
+    }
+
+    #[test]
+    fn cpu_usage_report() {
+        let usage = CpuUsage {
+            wall: Duration::from_secs(2),
+            process: CpuTime {
+                user: Duration::from_millis(2_500),
+                system: Duration::from_millis(500),
+            },
+            thread: CpuTime {
+                user: Duration::from_millis(1_200),
+                system: Duration::from_millis(300),
+            },
+        };
+
+        assert_eq!(usage.utilization(), 150.0);
+        assert_eq!(usage.thread_utilization(), 75.0);
+        assert_eq!(
+            usage.format_report(),
+            [
+                "Wall time: 2.000 seconds",
+                "User time: 2.500 seconds (thread: 1.200 seconds)",
+                "System time: 0.500000 seconds (thread: 0.300000 seconds)",
+                "CPU utilization: 150.00% (thread: 75.00%)",
+            ]
+            .join("\n")
+        );
+        assert_eq!(CpuUsage::default().utilization(), 0.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    /// `cargo test -- --show-output cpu_timer_measures_busy_loop`
+    fn cpu_timer_measures_busy_loop() -> io::Result<()> {
+        let timer = CpuTimer::start()?;
+
+        // Keep the CPU busy for a few clock ticks.
+        let busy = ExecutionTime::start();
+        let mut counter: u64 = 0;
+        while busy.get_duration() < Duration::from_millis(50) {
+            counter = std::hint::black_box(counter.wrapping_add(1));
+        }
+
+        let usage = timer.get_usage()?;
+        println!("{}", usage.format_report());
+
+        assert!(usage.wall >= Duration::from_millis(50));
+        assert!(usage.process.total() > Duration::ZERO);
+        assert!(usage.utilization() > 0.0);
+        assert_eq!(usage.format_report().lines().count(), 4);
+
+        Ok(())
+    }
+}
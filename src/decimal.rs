@@ -0,0 +1,110 @@
+/// Maximum number of fractional digits taken into account; the remaining
+/// digits are read but ignored, which is far below one nanosecond.
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+/// A non-negative decimal number read from text, kept as integers so that it
+/// can be scaled to nanoseconds without floating-point rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    integer: u128,
+    fraction: u128,
+    /// Number of digits of `fraction`.
+    scale: u32,
+}
+
+impl Decimal {
+    /// Reads a number such as "28" or "28.03" at the start of the text.
+    ///
+    /// The fractional part is introduced by any of the given separators and
+    /// must have at least one digit. Returns the number and the number of
+    /// bytes read, or `None` if the text does not start with a digit.
+    pub(crate) fn read(text: &str, separators: &[char]) -> Option<(Decimal, usize)> {
+        let integer_length: usize = count_digits(text);
+        if integer_length == 0 {
+            return None;
+        }
+
+        // Saturate instead of failing: the value is checked when scaled.
+        let integer: u128 = text[..integer_length].parse().unwrap_or(u128::MAX);
+        let mut decimal = Decimal {
+            integer,
+            fraction: 0,
+            scale: 0,
+        };
+
+        let rest: &str = &text[integer_length..];
+        let Some(separator) = rest.chars().next().filter(|c| separators.contains(c)) else {
+            return Some((decimal, integer_length));
+        };
+
+        let digits: &str = &rest[separator.len_utf8()..];
+        let fraction_length: usize = count_digits(digits);
+        if fraction_length == 0 {
+            return Some((decimal, integer_length));
+        }
+
+        let kept: &str = &digits[..fraction_length.min(MAX_FRACTION_DIGITS as usize)];
+        decimal.fraction = kept.parse().expect("only ASCII digits");
+        decimal.scale = kept.len() as u32;
+
+        Some((
+            decimal,
+            integer_length + separator.len_utf8() + fraction_length,
+        ))
+    }
+
+    /// Returns `true` if the number has no fractional part.
+    pub(crate) fn is_integer(&self) -> bool {
+        self.fraction == 0
+    }
+
+    /// Multiplies the number by a unit expressed in nanoseconds, truncating
+    /// below one nanosecond. Returns `None` on overflow.
+    pub(crate) fn to_nanos(self, unit_nanos: u128) -> Option<u128> {
+        let whole: u128 = self.integer.checked_mul(unit_nanos)?;
+        let part: u128 = self.fraction.checked_mul(unit_nanos)? / 10_u128.pow(self.scale);
+        whole.checked_add(part)
+    }
+}
+
+fn count_digits(text: &str) -> usize {
+    text.bytes().take_while(u8::is_ascii_digit).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_numbers() {
+        let (number, length) = Decimal::read("28.03S", &['.', ',']).unwrap();
+        assert_eq!(length, 5);
+        assert_eq!(number.to_nanos(1_000_000_000), Some(28_030_000_000));
+        assert!(!number.is_integer());
+
+        let (number, length) = Decimal::read("90m", &['.']).unwrap();
+        assert_eq!(length, 2);
+        assert_eq!(number.to_nanos(60), Some(5400));
+        assert!(number.is_integer());
+
+        // Comma as decimal separator, and a separator without digits.
+        assert_eq!(Decimal::read("0,5H", &['.', ',']).unwrap().1, 3);
+        assert_eq!(Decimal::read("5.S", &['.']).unwrap().1, 1);
+
+        assert_eq!(Decimal::read("S", &['.']), None);
+        assert_eq!(Decimal::read("", &['.']), None);
+    }
+
+    #[test]
+    fn scale_to_nanos() {
+        let (number, _) = Decimal::read("0.0000000019", &['.']).unwrap();
+        assert_eq!(number.to_nanos(1_000_000_000), Some(1));
+
+        let (number, _) = Decimal::read("1.123456789123456789123", &['.']).unwrap();
+        assert_eq!(number.to_nanos(1_000_000_000), Some(1_123_456_789));
+
+        let (number, _) =
+            Decimal::read("99999999999999999999999999999999999999999", &['.']).unwrap();
+        assert_eq!(number.to_nanos(1_000_000_000), None);
+    }
+}
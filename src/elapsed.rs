@@ -0,0 +1,86 @@
+use crate::{time::pad, Clock, DurationExtension, ExecutionTime, Time};
+use std::{fmt, time::Duration};
+
+/// A snapshot of the elapsed time of an [`ExecutionTime`], for use with
+/// `format!`, `write!` and `println!` without an intermediate `String`.
+///
+/// It is displayed like [`ExecutionTime::get_elapsed_time`], with the formatted
+/// time followed by the raw `Duration`. The formatter flags apply as described
+/// in the `Display` implementation of [`Time`]; in the compact form (`{:#}`)
+/// the raw `Duration` is omitted.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{ExecutionTime, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let timer = ExecutionTime::start_with_clock(clock.clone());
+/// clock.advance(Duration::from_millis(65_250));
+///
+/// assert_eq!(
+///     format!("{}", timer.elapsed()),
+///     "1 minute, 5.250 seconds (65.25s)"
+/// );
+/// assert_eq!(format!("{:#}", timer.elapsed()), "1m 5.25s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Elapsed {
+    duration: Duration,
+}
+
+impl Elapsed {
+    /// Gets the elapsed time as a `Duration`.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Gets the elapsed time as a `Time` struct.
+    pub fn time(&self) -> Time {
+        self.duration.get_time()
+    }
+}
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time: String = self.time().format_with_flags(f);
+        if f.alternate() {
+            pad(f, &time)
+        } else {
+            pad(f, &format!("{time} ({:?})", self.duration))
+        }
+    }
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Takes a snapshot of the elapsed time that implements `Display`.
+    pub fn elapsed(&self) -> Elapsed {
+        Elapsed {
+            duration: self.get_duration(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_display() {
+        let elapsed = Elapsed {
+            duration: Duration::new(93928, 30_000_000),
+        };
+
+        assert_eq!(
+            elapsed.to_string(),
+            "1 day, 2 hours, 5 minutes, 28.030 seconds (93928.03s)"
+        );
+        assert_eq!(
+            format!("{elapsed:.1}"),
+            "1 day, 2 hours, 5 minutes, 28.0 seconds (93928.03s)"
+        );
+        assert_eq!(format!("{elapsed:#}"), "1d 2h 5m 28.03s");
+        assert_eq!(format!("[{elapsed:>#18}]"), "[   1d 2h 5m 28.03s]");
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+/// Errors returned when a [`Time`](crate::Time) cannot be converted into a `Duration`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeError {
+    /// The hours are not in the range 0-23.
+    HoursOutOfRange(u8),
+    /// The minutes are not in the range 0-59.
+    MinutesOutOfRange(u8),
+    /// The seconds are not in the range [0, 60).
+    SecondsOutOfRange(f64),
+    /// The seconds are negative.
+    NegativeSeconds(f64),
+    /// The seconds are NaN or infinite.
+    NonFiniteSeconds(f64),
+    /// The time is too large to be represented by a `Duration`.
+    Overflow,
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeError::HoursOutOfRange(hours) => {
+                write!(f, "hours out of range (0-23): {hours}")
+            }
+            TimeError::MinutesOutOfRange(minutes) => {
+                write!(f, "minutes out of range (0-59): {minutes}")
+            }
+            TimeError::SecondsOutOfRange(seconds) => {
+                write!(f, "seconds out of range [0, 60): {seconds}")
+            }
+            TimeError::NegativeSeconds(seconds) => write!(f, "negative seconds: {seconds}"),
+            TimeError::NonFiniteSeconds(seconds) => write!(f, "non-finite seconds: {seconds}"),
+            TimeError::Overflow => write!(f, "time too large to be represented by a Duration"),
+        }
+    }
+}
+
+impl std::error::Error for TimeError {}
+
+/// The kinds of errors that can occur while parsing a time duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTimeErrorKind {
+    /// The input is empty.
+    Empty,
+    /// A character that is not valid at this position.
+    UnexpectedCharacter,
+    /// A number is missing or malformed.
+    InvalidNumber,
+    /// A number is not followed by a unit.
+    MissingUnit,
+    /// A unit that is not recognized.
+    UnknownUnit,
+    /// A unit that is recognized but not supported, such as years or months,
+    /// whose length depends on the calendar.
+    UnsupportedUnit,
+    /// A unit that appears more than once, or after a smaller unit.
+    UnitOrder,
+    /// A fractional value that is not in the smallest unit of the input.
+    MisplacedFraction,
+    /// The duration is too large to be represented by a `Duration`.
+    Overflow,
+}
+
+impl ParseTimeErrorKind {
+    fn description(&self) -> &'static str {
+        match self {
+            ParseTimeErrorKind::Empty => "empty input",
+            ParseTimeErrorKind::UnexpectedCharacter => "unexpected character",
+            ParseTimeErrorKind::InvalidNumber => "invalid number",
+            ParseTimeErrorKind::MissingUnit => "missing unit",
+            ParseTimeErrorKind::UnknownUnit => "unknown unit",
+            ParseTimeErrorKind::UnsupportedUnit => "unsupported unit",
+            ParseTimeErrorKind::UnitOrder => "unit repeated or out of order",
+            ParseTimeErrorKind::MisplacedFraction => "fraction only allowed in the smallest unit",
+            ParseTimeErrorKind::Overflow => "duration too large",
+        }
+    }
+}
+
+/// Error returned when a time duration cannot be parsed.
+///
+/// It records the kind of error, the failing token and its position
+/// (a byte offset into the input).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimeError {
+    kind: ParseTimeErrorKind,
+    token: String,
+    position: usize,
+}
+
+impl ParseTimeError {
+    pub(crate) fn new(kind: ParseTimeErrorKind, token: impl Into<String>, position: usize) -> Self {
+        Self {
+            kind,
+            token: token.into(),
+            position,
+        }
+    }
+
+    /// Gets the kind of error.
+    pub fn kind(&self) -> ParseTimeErrorKind {
+        self.kind
+    }
+
+    /// Gets the token that could not be parsed.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Gets the byte offset of the failing token in the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at position {}",
+            self.kind.description(),
+            self.position
+        )?;
+        if !self.token.is_empty() {
+            write!(f, ": {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseTimeError {}
@@ -0,0 +1,377 @@
+use crate::{FormatOptions, Precision, Time, Unit};
+use std::time::Duration;
+
+// Constants for seconds in a day, an hour, and a minute to improve readability and performance.
+const SECONDS_IN_DAY: u64 = 86400;
+const SECONDS_IN_HOUR: u64 = 3600;
+const SECONDS_IN_MINUTE: u64 = 60;
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Lossless representation of a time duration split into days, hours,
+/// minutes, whole seconds and nanoseconds.
+///
+/// Unlike [`Time`], whose seconds are an `f64`, all components are integers,
+/// so the conversion from and back to `Duration` round-trips exactly.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::ExactTime;
+/// use std::time::Duration;
+///
+/// let duration = Duration::new(93928, 30_000_001);
+/// let exact = ExactTime::from(duration);
+///
+/// assert_eq!(exact.days(), 1);
+/// assert_eq!(exact.hours(), 2);
+/// assert_eq!(exact.minutes(), 5);
+/// assert_eq!(exact.seconds(), 28);
+/// assert_eq!(exact.nanos(), 30_000_001);
+/// assert_eq!(Duration::from(exact), duration);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExactTime {
+    days: u64,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    nanos: u32,
+}
+
+impl ExactTime {
+    /// Gets the number of days.
+    pub fn days(&self) -> u64 {
+        self.days
+    }
+
+    /// Gets the number of hours (0-23).
+    pub fn hours(&self) -> u8 {
+        self.hours
+    }
+
+    /// Gets the number of minutes (0-59).
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    /// Gets the number of whole seconds (0-59).
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Gets the fractional part of the seconds, in nanoseconds.
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Converts into a `Time` struct, whose seconds are an `f64`.
+    pub fn to_time(&self) -> Time {
+        // The seconds fit in a `u64` of nanoseconds, which is converted with a
+        // single rounding, so the result is the `f64` closest to the exact value.
+        let nanos: u64 =
+            u64::from(self.seconds) * u64::from(NANOS_PER_SECOND) + u64::from(self.nanos);
+
+        Time {
+            days: self.days,
+            hours: self.hours,
+            minutes: self.minutes,
+            seconds: nanos as f64 / f64::from(NANOS_PER_SECOND),
+        }
+    }
+
+    /// Formats the time duration into a human-readable string.
+    ///
+    /// See [`Time::format_time`].
+    pub fn format_time(&self) -> String {
+        self.format_verbose(self.calculate_decimal())
+    }
+
+    /// Formats the time duration into the verbose form, showing the seconds
+    /// with the given number of decimal places.
+    pub(crate) fn format_verbose(&self, decimal: usize) -> String {
+        self.format_with_options(&FormatOptions::new().with_precision(Precision::Fixed(decimal)))
+    }
+
+    /// Formats the time duration into the compact form, such as "1d 2h 5m 28.03s".
+    ///
+    /// Zero components are omitted, except for the seconds when all components
+    /// are zero. Unless a number of decimal places is given, the seconds use
+    /// the adaptive precision with the trailing zeros removed.
+    pub(crate) fn format_compact(&self, decimal: Option<usize>) -> String {
+        // Round first, so that the rounding carries into the larger units.
+        let (rounded, seconds) = match decimal {
+            Some(decimal) => {
+                let rounded: ExactTime = self.round_to(decimal);
+                (rounded, rounded.format_seconds(decimal))
+            }
+            None => {
+                let (rounded, decimal) = self.round_adaptive();
+                (rounded, trim_fraction(rounded.format_seconds(decimal)))
+            }
+        };
+
+        let mut parts = Vec::new();
+
+        let components = [
+            (rounded.days, Unit::Day),
+            (u64::from(rounded.hours), Unit::Hour),
+            (u64::from(rounded.minutes), Unit::Minute),
+        ];
+
+        for (value, unit) in components {
+            if value > 0 {
+                parts.push(format!("{value}{}", unit.symbol()));
+            }
+        }
+
+        if parts.is_empty() || rounded.seconds > 0 || rounded.nanos > 0 {
+            parts.push(format!("{seconds}{}", Unit::Second.symbol()));
+        }
+
+        parts.join(" ")
+    }
+
+    /// Rounds the whole time duration, half up, to the given number of
+    /// decimal places of the seconds, so that the rounding carries into the
+    /// minutes, hours and days. It is left unchanged if the result overflows.
+    pub(crate) fn round_to(&self, decimal: usize) -> ExactTime {
+        // Size of the last displayed digit, in nanoseconds.
+        let step: u128 = 10_u128.pow((9 - decimal.min(9)) as u32);
+        let nanos: u128 = Duration::from(*self).as_nanos();
+        duration_from_nanos((nanos + step / 2) / step * step).map_or(*self, ExactTime::from)
+    }
+
+    /// Rounds the time duration to the adaptive precision of
+    /// [`ExactTime::calculate_decimal`], and returns it with its number of
+    /// decimal places.
+    ///
+    /// The precision is taken from the rounded value, since the rounding may
+    /// reach the next magnitude (0.9999996 seconds is shown as "1.000").
+    pub(crate) fn round_adaptive(&self) -> (ExactTime, usize) {
+        let decimal: usize = self.round_to(self.calculate_decimal()).calculate_decimal();
+        (self.round_to(decimal), decimal)
+    }
+
+    /// Formats the seconds with the given number of decimal places,
+    /// rounding half away from zero using integer arithmetic only.
+    pub(crate) fn format_seconds(&self, decimal: usize) -> String {
+        let decimal = decimal.min(9);
+
+        // Size of the last displayed digit, in nanoseconds.
+        let step: u32 = 10_u32.pow((9 - decimal) as u32);
+        let mut fraction: u32 = (self.nanos + step / 2) / step;
+        let mut seconds: u64 = u64::from(self.seconds);
+
+        // Rounding up may carry into the whole seconds (e.g. 4.9999 -> 5.000).
+        if fraction == 10_u32.pow(decimal as u32) {
+            fraction = 0;
+            seconds += 1;
+        }
+
+        if decimal == 0 {
+            format!("{seconds}")
+        } else {
+            format!("{seconds}.{fraction:0decimal$}")
+        }
+    }
+
+    /// Calculates the appropriate number of decimal places for displaying seconds.
+    ///
+    /// This function determines the number of decimal places to show for the seconds
+    /// value based on its magnitude. It aims to provide a balance between precision
+    /// and readability.
+    pub(crate) fn calculate_decimal(&self) -> usize {
+        if self.seconds == 0 && self.nanos == 0 {
+            // Handles the case where the seconds are zero. Show one decimal place.
+            1
+        } else if self.seconds >= 1 {
+            // If seconds is greater than or equal to 1, show three decimal places.
+            3
+        } else if self.nanos >= 1_000_000 {
+            // If seconds is greater than or equal to 0.001, show six decimal places.
+            6
+        } else {
+            // Otherwise, show nine decimal places for higher precision.
+            9
+        }
+    }
+}
+
+/// Removes the trailing zeros of the fractional part of a number, and the
+/// decimal point if nothing is left after it.
+pub(crate) fn trim_fraction(number: String) -> String {
+    if !number.contains('.') {
+        return number;
+    }
+    number
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+impl From<Duration> for ExactTime {
+    fn from(duration: Duration) -> Self {
+        let all_seconds: u64 = duration.as_secs();
+
+        let remaining_day = all_seconds % SECONDS_IN_DAY;
+        let remaining_hour = remaining_day % SECONDS_IN_HOUR;
+
+        Self {
+            days: all_seconds / SECONDS_IN_DAY,
+            hours: (remaining_day / SECONDS_IN_HOUR) as u8,
+            minutes: (remaining_hour / SECONDS_IN_MINUTE) as u8,
+            seconds: (remaining_hour % SECONDS_IN_MINUTE) as u8,
+            nanos: duration.subsec_nanos(),
+        }
+    }
+}
+
+impl From<ExactTime> for Duration {
+    fn from(time: ExactTime) -> Self {
+        // Every `ExactTime` comes from a `Duration`, so this cannot overflow.
+        let seconds: u64 = time.days * SECONDS_IN_DAY
+            + u64::from(time.hours) * SECONDS_IN_HOUR
+            + u64::from(time.minutes) * SECONDS_IN_MINUTE
+            + u64::from(time.seconds);
+
+        Duration::new(seconds, time.nanos)
+    }
+}
+
+impl From<&Time> for ExactTime {
+    /// Converts a `Time` into its exact representation.
+    ///
+    /// The seconds are rounded to the nearest nanosecond. Components out of
+    /// their usual range are carried into the larger units; negative or NaN
+    /// seconds count as zero and the total saturates at `Duration::MAX`.
+    fn from(time: &Time) -> Self {
+        let nanos: u128 = total_nanos(
+            time.days,
+            u64::from(time.hours),
+            u64::from(time.minutes),
+            time.seconds,
+        );
+
+        Self::from(duration_from_nanos(nanos).unwrap_or(Duration::MAX))
+    }
+}
+
+/// Adds up the components of a time duration, in nanoseconds.
+///
+/// The seconds are rounded to the nearest nanosecond; negative or NaN seconds
+/// count as zero.
+pub(crate) fn total_nanos(days: u64, hours: u64, minutes: u64, seconds: f64) -> u128 {
+    let nanos_per_second = u128::from(NANOS_PER_SECOND);
+
+    let whole_seconds: u128 = u128::from(days) * u128::from(SECONDS_IN_DAY)
+        + u128::from(hours) * u128::from(SECONDS_IN_HOUR)
+        + u128::from(minutes) * u128::from(SECONDS_IN_MINUTE);
+
+    // Float to integer casts saturate, so huge seconds cannot wrap around.
+    let second_nanos: u128 = (seconds * f64::from(NANOS_PER_SECOND)).round() as u128;
+
+    (whole_seconds * nanos_per_second).saturating_add(second_nanos)
+}
+
+/// Converts a number of nanoseconds into a `Duration`, or `None` if it
+/// exceeds `Duration::MAX`.
+pub(crate) fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let nanos_per_second = u128::from(NANOS_PER_SECOND);
+    let seconds = u64::try_from(nanos / nanos_per_second).ok()?;
+    let subsec_nanos = (nanos % nanos_per_second) as u32;
+    Some(Duration::new(seconds, subsec_nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExtension;
+
+    #[test]
+    fn round_trip_is_exact() {
+        let durations = [
+            Duration::ZERO,
+            Duration::new(0, 1),
+            Duration::new(59, 999_999_999),
+            Duration::new(93928, 30_000_001),
+            // About 30 years: far beyond the exact range of `f64` nanoseconds.
+            Duration::new(946_080_000, 123_456_789),
+            Duration::MAX,
+        ];
+
+        for duration in durations {
+            let exact = ExactTime::from(duration);
+            assert_eq!(Duration::from(exact), duration);
+            assert_eq!(duration.get_exact_time(), exact);
+        }
+    }
+
+    #[test]
+    fn keeps_nanoseconds_of_long_durations() {
+        // 10_950 days, 23:59:59.000000001
+        let duration = Duration::new(10_950 * 86400 + 86399, 1);
+        let exact = duration.get_exact_time();
+
+        assert_eq!(exact.days(), 10_950);
+        assert_eq!(exact.hours(), 23);
+        assert_eq!(exact.minutes(), 59);
+        assert_eq!(exact.seconds(), 59);
+        assert_eq!(exact.nanos(), 1);
+
+        assert_eq!(
+            exact.format_time(),
+            "10950 days, 23 hours, 59 minutes, 59.000 seconds"
+        );
+        assert_eq!(duration.get_time().seconds, 59.000000001);
+    }
+
+    #[test]
+    fn from_time() {
+        let time = Time {
+            days: 0,
+            hours: 0,
+            minutes: 90,
+            seconds: 61.5,
+        };
+        let exact = ExactTime::from(&time);
+
+        assert_eq!(exact.hours(), 1);
+        assert_eq!(exact.minutes(), 31);
+        assert_eq!(exact.seconds(), 1);
+        assert_eq!(exact.nanos(), 500_000_000);
+
+        let negative = Time {
+            seconds: -1.0,
+            ..Time::default()
+        };
+        assert_eq!(ExactTime::from(&negative), ExactTime::default());
+    }
+
+    #[test]
+    fn format_compact() {
+        let exact = ExactTime::from(Duration::new(93928, 30_000_000));
+        assert_eq!(exact.format_compact(None), "1d 2h 5m 28.03s");
+        assert_eq!(exact.format_compact(Some(1)), "1d 2h 5m 28.0s");
+
+        let exact = ExactTime::from(Duration::from_secs(86400 + 60));
+        assert_eq!(exact.format_compact(None), "1d 1m");
+
+        let exact = ExactTime::from(Duration::new(0, 80_057));
+        assert_eq!(exact.format_compact(None), "0.000080057s");
+
+        assert_eq!(ExactTime::default().format_compact(None), "0s");
+    }
+
+    #[test]
+    fn format_seconds_rounding() {
+        let exact = ExactTime::from(Duration::new(4, 999_600_000));
+        assert_eq!(exact.format_seconds(0), "5");
+        assert_eq!(exact.format_seconds(3), "5.000");
+        assert_eq!(exact.format_seconds(4), "4.9996");
+        assert_eq!(exact.format_seconds(9), "4.999600000");
+
+        let exact = ExactTime::from(Duration::new(0, 80_057));
+        assert_eq!(exact.format_seconds(6), "0.000080");
+        assert_eq!(exact.format_seconds(9), "0.000080057");
+    }
+}
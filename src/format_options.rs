@@ -0,0 +1,457 @@
+use crate::{
+    exact_time::trim_fraction, Clock, DurationExtension, ExactTime, ExecutionTime, Locale,
+    PluralRule, SingularPlural, Time, Unit,
+};
+use std::{sync::Arc, time::Duration};
+
+/// The number of decimal places of the smallest unit shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Precision {
+    /// Decimal places chosen from the magnitude of the value, as in
+    /// [`Time::format_time`]: 1 for zero, 3 from one unit, 6 from a
+    /// thousandth of a unit and 9 below.
+    #[default]
+    Adaptive,
+    /// A fixed number of decimal places.
+    Fixed(usize),
+    /// A number of significant digits, such as "0.0000801" for 3 digits.
+    /// The integer digits are never rounded.
+    Significant(usize),
+}
+
+/// Options controlling how a time duration is formatted.
+///
+/// The default options reproduce [`Time::format_time`] and
+/// [`ExecutionTime::get_elapsed_time`]: days, hours, minutes and seconds,
+/// leading zero components skipped, adaptive precision, English words, ", "
+/// as separator and the raw `Duration` after the elapsed time.
+///
+/// The components go from the largest unit (`max_unit`) down to the smallest
+/// (`min_unit`), which carries the fractional part.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{FormatOptions, Precision, Time, Unit};
+///
+/// let time = Time { days: 1, hours: 0, minutes: 5, seconds: 28.03 };
+/// assert_eq!(
+///     time.format_with_options(&FormatOptions::default()),
+///     time.format_time()
+/// );
+///
+/// let options = FormatOptions::new()
+///     .with_skip_zero(true)
+///     .with_max_unit(Unit::Hour)
+///     .with_min_unit(Unit::Minute)
+///     .with_precision(Precision::Fixed(1))
+///     .with_separator(" ");
+/// assert_eq!(time.format_with_options(&options), "24 hours 5.5 minutes");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    skip_zero: bool,
+    min_unit: Unit,
+    max_unit: Unit,
+    precision: Precision,
+    separator: String,
+    conjunction: Option<String>,
+    locale: Option<Arc<Locale>>,
+    duration_suffix: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            skip_zero: false,
+            min_unit: Unit::Second,
+            max_unit: Unit::Day,
+            precision: Precision::Adaptive,
+            separator: ", ".to_string(),
+            conjunction: None,
+            locale: None,
+            duration_suffix: true,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips all the zero components, not only the leading ones
+    /// ("1 day, 5 minutes" instead of "1 day, 0 hours, 5 minutes, 0.0 seconds").
+    /// A zero duration is still shown in the smallest unit.
+    pub fn with_skip_zero(mut self, skip_zero: bool) -> Self {
+        self.skip_zero = skip_zero;
+        self
+    }
+
+    /// Sets the smallest unit shown, which carries the fractional part.
+    pub fn with_min_unit(mut self, unit: Unit) -> Self {
+        self.min_unit = unit;
+        self
+    }
+
+    /// Sets the largest unit shown; larger amounts are expressed in it
+    /// (such as "26 hours"). It is raised to the smallest unit if needed.
+    pub fn with_max_unit(mut self, unit: Unit) -> Self {
+        self.max_unit = unit;
+        self
+    }
+
+    /// Sets the precision of the smallest unit.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the separator between the components.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the separator before the last component, such as " and ".
+    /// Without a conjunction, the separator is used.
+    pub fn with_conjunction(mut self, conjunction: impl Into<String>) -> Self {
+        self.conjunction = Some(conjunction.into());
+        self
+    }
+
+    /// Uses the unit words, plural rule and decimal separator of the locale,
+    /// and sets the separator and conjunction to those of the locale.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{FormatOptions, Locale, Time, Unit};
+    ///
+    /// let time = Time { days: 0, hours: 1, minutes: 0, seconds: 0.0025 };
+    /// let options = FormatOptions::new()
+    ///     .with_locale(Locale::french())
+    ///     .with_skip_zero(true)
+    ///     .with_min_unit(Unit::Millisecond);
+    ///
+    /// assert_eq!(time.format_with_options(&options), "1 heure et 2,500 millisecondes");
+    /// ```
+    pub fn with_locale(mut self, locale: impl Into<Arc<Locale>>) -> Self {
+        let locale: Arc<Locale> = locale.into();
+        self.separator = locale.separator().to_string();
+        self.conjunction = Some(locale.conjunction().to_string());
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Shows the raw `Duration` after the elapsed time, such as
+    /// "1.000 seconds (1s)", in [`ExecutionTime::get_elapsed_time_with_options`].
+    pub fn with_duration_suffix(mut self, duration_suffix: bool) -> Self {
+        self.duration_suffix = duration_suffix;
+        self
+    }
+
+    /// Gets the number of decimal places for `rest` nanoseconds in `unit`.
+    fn decimal(&self, rest: u128, unit: Unit) -> usize {
+        // Decimal places below one nanosecond are always zero.
+        let max: usize = match unit {
+            Unit::Nanosecond => 0,
+            Unit::Microsecond => 3,
+            Unit::Millisecond => 6,
+            _ => 9,
+        };
+
+        let decimal: usize = match self.precision {
+            Precision::Adaptive if rest == 0 => 1,
+            Precision::Adaptive if rest >= unit.nanos() => 3,
+            Precision::Adaptive if rest * 1000 >= unit.nanos() => 6,
+            Precision::Adaptive => 9,
+            Precision::Fixed(decimal) => decimal,
+            Precision::Significant(_) if rest == 0 => 0,
+            Precision::Significant(digits) => {
+                let digits: usize = digits.max(1);
+                let integer: u128 = rest / unit.nanos();
+                if integer > 0 {
+                    digits.saturating_sub(integer.to_string().len())
+                } else {
+                    // Count the zeros between the decimal point and the first digit.
+                    let zeros: usize = (0..max)
+                        .take_while(|zeros| rest * 10_u128.pow(*zeros as u32 + 1) < unit.nanos())
+                        .count();
+                    zeros + digits
+                }
+            }
+        };
+
+        decimal.min(max)
+    }
+
+    /// Formats a number followed by the word of the unit for its plural category.
+    fn format_unit(&self, number: String, unit: Unit) -> String {
+        match &self.locale {
+            Some(locale) => locale.format_unit(&number, unit),
+            None => {
+                let word: &str = unit.for_category(PluralRule::English.category(&number));
+                format!("{number} {word}")
+            }
+        }
+    }
+
+    /// Joins the components, with the conjunction before the last one.
+    fn join(&self, mut parts: Vec<String>) -> String {
+        let Some(conjunction) = &self.conjunction else {
+            return parts.join(&self.separator);
+        };
+        let Some(last) = parts.pop() else {
+            return String::new();
+        };
+
+        if parts.is_empty() {
+            last
+        } else {
+            format!("{}{conjunction}{last}", parts.join(&self.separator))
+        }
+    }
+}
+
+impl Time {
+    /// Formats the time duration with the given options.
+    ///
+    /// See [`FormatOptions`].
+    pub fn format_with_options(&self, options: &FormatOptions) -> String {
+        self.to_exact().format_with_options(options)
+    }
+}
+
+impl ExactTime {
+    /// Formats the time duration with the given options.
+    ///
+    /// See [`FormatOptions`].
+    pub fn format_with_options(&self, options: &FormatOptions) -> String {
+        let min_unit: Unit = options.min_unit;
+        let max_unit: Unit = options.max_unit.max(min_unit);
+
+        let mut rest: u128 = Duration::from(*self).as_nanos();
+        let mut parts: Vec<String> = Vec::new();
+
+        // The integer components, from the largest unit.
+        for unit in Unit::ALL.into_iter().rev() {
+            if unit > max_unit || unit <= min_unit {
+                continue;
+            }
+
+            let value: u128 = rest / unit.nanos();
+            rest %= unit.nanos();
+
+            if value > 0 || (!options.skip_zero && !parts.is_empty()) {
+                parts.push(options.format_unit(value.to_string(), unit));
+            }
+        }
+
+        // The smallest unit, with the remaining fraction.
+        if rest > 0 || parts.is_empty() || !options.skip_zero {
+            let decimal: usize = options.decimal(rest, min_unit);
+            let scale: u128 = 10_u128.pow(decimal as u32);
+            // Round half up.
+            let scaled: u128 = (rest * scale + min_unit.nanos() / 2) / min_unit.nanos();
+
+            let number: String = if decimal == 0 {
+                scaled.to_string()
+            } else {
+                format!("{}.{:0decimal$}", scaled / scale, scaled % scale)
+            };
+            let number: String = match options.precision {
+                Precision::Significant(_) => trim_fraction(number),
+                _ => number,
+            };
+            parts.push(options.format_unit(number, min_unit));
+        }
+
+        options.join(parts)
+    }
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Formats the elapsed time with the given options.
+    ///
+    /// With the default options this is the same as
+    /// [`ExecutionTime::get_elapsed_time`].
+    pub fn get_elapsed_time_with_options(&self, options: &FormatOptions) -> String {
+        let duration: Duration = self.get_duration();
+        let time: String = duration.get_exact_time().format_with_options(options);
+
+        if options.duration_suffix {
+            format!("{time} ({duration:?})")
+        } else {
+            time
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+
+    const DURATIONS: [Duration; 9] = [
+        Duration::ZERO,
+        Duration::new(0, 57),
+        Duration::new(0, 80_057),
+        Duration::new(0, 15_200_000),
+        Duration::new(5, 80_000_000),
+        Duration::new(59, 999_999_999),
+        Duration::new(65, 12_345),
+        Duration::new(3700, 56_891_730),
+        Duration::new(172_800, 0),
+    ];
+
+    fn format(duration: Duration, options: &FormatOptions) -> String {
+        duration.get_exact_time().format_with_options(options)
+    }
+
+    #[test]
+    fn default_options_match_format_time() {
+        for duration in DURATIONS {
+            assert_eq!(
+                format(duration, &FormatOptions::default()),
+                duration.get_time().format_time()
+            );
+        }
+    }
+
+    #[test]
+    fn skip_zero_components() {
+        let options = FormatOptions::new().with_skip_zero(true);
+
+        assert_eq!(format(Duration::from_secs(172_800), &options), "2 days");
+        assert_eq!(
+            format(Duration::from_secs(86_700), &options),
+            "1 day, 5 minutes"
+        );
+        assert_eq!(format(Duration::ZERO, &options), "0.0 seconds");
+    }
+
+    #[test]
+    fn unit_range() {
+        let duration = Duration::new(93928, 30_000_000); // 1d 2h 5m 28.03s
+
+        let options = FormatOptions::new().with_max_unit(Unit::Hour);
+        assert_eq!(
+            format(duration, &options),
+            "26 hours, 5 minutes, 28.030 seconds"
+        );
+
+        let options = FormatOptions::new().with_min_unit(Unit::Hour);
+        assert_eq!(format(duration, &options), "1 day, 2.091 hours");
+
+        let options = FormatOptions::new()
+            .with_min_unit(Unit::Millisecond)
+            .with_max_unit(Unit::Second);
+        assert_eq!(
+            format(duration, &options),
+            "93928 seconds, 30.000 milliseconds"
+        );
+
+        let options = FormatOptions::new()
+            .with_min_unit(Unit::Nanosecond)
+            .with_max_unit(Unit::Millisecond)
+            .with_skip_zero(true);
+        assert_eq!(
+            format(Duration::new(0, 80_057), &options),
+            "80 microseconds, 57 nanoseconds"
+        );
+
+        // The largest unit is raised to the smallest one.
+        let options = FormatOptions::new()
+            .with_min_unit(Unit::Minute)
+            .with_max_unit(Unit::Second);
+        assert_eq!(format(Duration::from_secs(90), &options), "1.500 minutes");
+    }
+
+    #[test]
+    fn precision() {
+        let fixed = |decimal| FormatOptions::new().with_precision(Precision::Fixed(decimal));
+        assert_eq!(format(Duration::new(5, 80_000_000), &fixed(0)), "5 seconds");
+        assert_eq!(
+            format(Duration::new(5, 80_000_000), &fixed(1)),
+            "5.1 seconds"
+        );
+        assert_eq!(
+            format(Duration::new(0, 57), &fixed(12)),
+            "0.000000057 seconds"
+        );
+
+        let significant =
+            |digits| FormatOptions::new().with_precision(Precision::Significant(digits));
+        assert_eq!(
+            format(Duration::new(0, 80_057), &significant(3)),
+            "0.0000801 seconds"
+        );
+        assert_eq!(
+            format(Duration::new(5, 80_000_000), &significant(3)),
+            "5.08 seconds"
+        );
+        assert_eq!(
+            format(Duration::new(28, 30_000_000), &significant(1)),
+            "28 seconds"
+        );
+        assert_eq!(format(Duration::new(1, 0), &significant(3)), "1 second");
+        assert_eq!(format(Duration::ZERO, &significant(3)), "0 seconds");
+    }
+
+    #[test]
+    fn elapsed_time_with_options() {
+        let clock = ManualClock::new();
+        let timer = ExecutionTime::start_with_clock(clock.clone());
+        clock.advance(Duration::new(3700, 56_891_730));
+
+        assert_eq!(
+            timer.get_elapsed_time_with_options(&FormatOptions::default()),
+            timer.get_elapsed_time()
+        );
+
+        let options = FormatOptions::new()
+            .with_separator(" ")
+            .with_duration_suffix(false);
+        assert_eq!(
+            timer.get_elapsed_time_with_options(&options),
+            "1 hour 1 minute 40.057 seconds"
+        );
+
+        let options = FormatOptions::new().with_conjunction(" and ");
+        assert_eq!(
+            timer.get_elapsed_time_with_options(&options),
+            "1 hour, 1 minute and 40.057 seconds (3700.05689173s)"
+        );
+    }
+
+    #[test]
+    fn localized_options() {
+        let options = FormatOptions::new().with_locale(Locale::german());
+        assert_eq!(
+            format(Duration::from_secs(3905), &options),
+            "1 Stunde, 5 Minuten und 5,000 Sekunden"
+        );
+
+        let options = options
+            .with_min_unit(Unit::Nanosecond)
+            .with_max_unit(Unit::Millisecond)
+            .with_skip_zero(true)
+            .with_separator(" ")
+            .with_conjunction(" ");
+        assert_eq!(
+            format(Duration::new(0, 1_000_057), &options),
+            "1 Millisekunde 57 Nanosekunden"
+        );
+
+        assert_eq!(
+            FormatOptions::new().with_locale(Locale::spanish()),
+            FormatOptions::new().with_locale(Locale::get("es").unwrap())
+        );
+        assert_ne!(
+            FormatOptions::new().with_locale(Locale::english()),
+            FormatOptions::new()
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{
+    exact_time::trim_fraction, Clock, DurationExtension, ExactTime, ExecutionTime, PluralRule,
+    SingularPlural, Time, Unit,
+};
+use std::time::Duration;
+
+/// The styles available to format a [`Time`].
+///
+/// All styles are built on the same decomposition into days, hours, minutes
+/// and seconds. For 1 day, 2 hours, 5 minutes and 28.03 seconds:
+///
+/// | Style               | Output                                       |
+/// |---------------------|----------------------------------------------|
+/// | `Verbose`           | `1 day, 2 hours, 5 minutes, 28.030 seconds`  |
+/// | `Compact`           | `1d 2h 5m 28.03s`                            |
+/// | `Clock`             | `1d 02:05:28.030`                            |
+/// | `Largest(2)`        | `1d 2h`                                      |
+/// | `Natural`           | `1.087 days`                                 |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FormatStyle {
+    /// The default style of [`Time::format_time`].
+    #[default]
+    Verbose,
+    /// Abbreviated units, without the zero components.
+    Compact,
+    /// Hours, minutes and seconds separated by colons, preceded by the days if any.
+    Clock,
+    /// Abbreviated units, keeping only the given number of units counted from
+    /// the largest non-zero one. The smaller units are truncated.
+    Largest(usize),
+    /// A single number in the most natural unit, from nanoseconds to years,
+    /// such as "80.057 microseconds" or "15.2 milliseconds".
+    Natural,
+}
+
+impl Time {
+    /// Formats the time duration in the given style.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{FormatStyle, Time};
+    ///
+    /// let time = Time { days: 1, hours: 2, minutes: 5, seconds: 28.03 };
+    ///
+    /// assert_eq!(time.format_with(FormatStyle::Compact), "1d 2h 5m 28.03s");
+    /// assert_eq!(time.format_with(FormatStyle::Clock), "1d 02:05:28.030");
+    /// assert_eq!(time.format_with(FormatStyle::Largest(2)), "1d 2h");
+    /// assert_eq!(time.format_with(FormatStyle::Natural), "1.087 days");
+    /// ```
+    pub fn format_with(&self, style: FormatStyle) -> String {
+        self.to_exact().format_with(style)
+    }
+}
+
+impl ExactTime {
+    /// Formats the time duration in the given style.
+    pub fn format_with(&self, style: FormatStyle) -> String {
+        match style {
+            FormatStyle::Verbose => self.format_time(),
+            FormatStyle::Compact => self.format_compact(None),
+            FormatStyle::Clock => self.format_clock(),
+            FormatStyle::Largest(count) => self.format_largest(count),
+            FormatStyle::Natural => self.format_natural(),
+        }
+    }
+
+    /// Formats the time duration as "1d 02:05:28.030".
+    fn format_clock(&self) -> String {
+        // Round the whole duration to the displayed precision first, so that
+        // the rounding carries into the minutes, hours and days.
+        let (rounded, decimal) = self.round_adaptive();
+
+        // Pad the whole seconds to two digits.
+        let width: usize = if decimal > 0 { decimal + 3 } else { 2 };
+        let seconds: String = rounded.format_seconds(decimal);
+        let clock = format!(
+            "{:02}:{:02}:{seconds:0>width$}",
+            rounded.hours(),
+            rounded.minutes()
+        );
+
+        if rounded.days() > 0 {
+            format!("{}d {clock}", rounded.days())
+        } else {
+            clock
+        }
+    }
+
+    /// Formats the `count` units starting from the largest non-zero one, such as "1d 2h".
+    fn format_largest(&self, count: usize) -> String {
+        // Round first, so that the rounding of the seconds carries into the larger units.
+        let (rounded, decimal) = self.round_adaptive();
+
+        let components = [
+            (rounded.days(), Unit::Day),
+            (u64::from(rounded.hours()), Unit::Hour),
+            (u64::from(rounded.minutes()), Unit::Minute),
+        ];
+
+        // Skip the leading zero components; at least one unit is always shown.
+        let first: usize = components
+            .iter()
+            .position(|(value, _)| *value > 0)
+            .unwrap_or(components.len());
+        let last: usize = first + count.max(1);
+
+        let mut parts: Vec<String> = components[first..last.min(components.len())]
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{value}{}", unit.symbol()))
+            .collect();
+
+        let has_fraction: bool = rounded.seconds() > 0 || rounded.nanos() > 0;
+        if last > components.len() && (parts.is_empty() || has_fraction) {
+            let seconds = trim_fraction(rounded.format_seconds(decimal));
+            parts.push(format!("{seconds}{}", Unit::Second.symbol()));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl ExactTime {
+    /// Formats the time duration in the largest unit that keeps the value at
+    /// least 1, with up to three decimal places, such as "80.057 microseconds".
+    fn format_natural(&self) -> String {
+        let nanos: u128 = Duration::from(*self).as_nanos();
+
+        // Value in thousandths of the unit, rounded half up. The rounding may
+        // reach the next unit (999.9996 microseconds is 1 millisecond).
+        let scaled = |unit: Unit| (nanos * 1000 + unit.nanos() / 2) / unit.nanos();
+
+        let unit: Unit = Unit::ALL
+            .into_iter()
+            .rev()
+            .find(|unit| scaled(*unit) >= 1000)
+            .unwrap_or(Unit::Second);
+
+        let value: u128 = scaled(unit);
+        let number: String = trim_fraction(format!("{}.{:03}", value / 1000, value % 1000));
+        let word: &str = unit.for_category(PluralRule::English.category(&number));
+
+        format!("{number} {word}")
+    }
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Formats the elapsed time in the given style.
+    ///
+    /// With [`FormatStyle::Verbose`] this is the same as
+    /// [`ExecutionTime::get_elapsed_time`], including the raw `Duration`;
+    /// the other styles only show the formatted time.
+    pub fn get_elapsed_time_with(&self, style: FormatStyle) -> String {
+        match style {
+            FormatStyle::Verbose => self.get_elapsed_time(),
+            _ => self.get_duration().get_exact_time().format_with(style),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExtension, ManualClock};
+    use std::time::Duration;
+
+    fn format(duration: Duration, style: FormatStyle) -> String {
+        duration.get_time().format_with(style)
+    }
+
+    #[test]
+    fn verbose_style() {
+        let duration = Duration::new(93928, 30_000_000);
+        assert_eq!(
+            format(duration, FormatStyle::Verbose),
+            duration.get_time().format_time()
+        );
+    }
+
+    #[test]
+    fn clock_style() {
+        let style = FormatStyle::Clock;
+        assert_eq!(
+            format(Duration::new(93928, 30_000_000), style),
+            "1d 02:05:28.030"
+        );
+        assert_eq!(
+            format(Duration::new(3700, 56_891_730), style),
+            "01:01:40.057"
+        );
+        assert_eq!(format(Duration::new(65, 0), style), "00:01:05.000");
+        assert_eq!(
+            format(Duration::new(0, 15_200_000), style),
+            "00:00:00.015200"
+        );
+        assert_eq!(format(Duration::ZERO, style), "00:00:00.0");
+    }
+
+    #[test]
+    fn clock_style_rounding_carries() {
+        let style = FormatStyle::Clock;
+        assert_eq!(
+            format(Duration::from_nanos(9_999_600_000), style),
+            "00:00:10.000"
+        );
+        assert_eq!(
+            format(Duration::from_nanos(59_999_600_000), style),
+            "00:01:00.0"
+        );
+        assert_eq!(
+            format(Duration::from_nanos(3_599_999_999_999), style),
+            "01:00:00.0"
+        );
+        assert_eq!(
+            format(Duration::from_nanos(86_399_999_999_999), style),
+            "1d 00:00:00.0"
+        );
+
+        // The precision follows the rounded value, which is shown like the
+        // exact duration it is equal to.
+        assert_eq!(
+            format(Duration::from_nanos(59_999_600_000), style),
+            format(Duration::from_secs(60), style)
+        );
+        assert_eq!(
+            format(Duration::from_nanos(999_999_600), style),
+            "00:00:01.000"
+        );
+        assert_eq!(
+            format(Duration::from_nanos(999_999_400), style),
+            "00:00:00.999999"
+        );
+    }
+
+    #[test]
+    fn compact_style_rounding_carries() {
+        let style = FormatStyle::Compact;
+        assert_eq!(format(Duration::from_nanos(9_999_600_000), style), "10s");
+        assert_eq!(format(Duration::from_nanos(59_999_600_000), style), "1m");
+        assert_eq!(format(Duration::from_nanos(3_599_999_600_000), style), "1h");
+        assert_eq!(
+            format(Duration::from_nanos(86_399_999_999_999), style),
+            "1d"
+        );
+        assert_eq!(format(Duration::from_nanos(999_999_600), style), "1s");
+        assert_eq!(
+            format!("{:#}", Duration::from_nanos(59_999_600_000).get_time()),
+            "1m"
+        );
+        assert_eq!(
+            format!("{:#.1}", Duration::from_nanos(119_960_000_000).get_time()),
+            "2m"
+        );
+    }
+
+    #[test]
+    fn largest_style_rounding_carries() {
+        let style = FormatStyle::Largest(2);
+        assert_eq!(format(Duration::from_nanos(59_999_600_000), style), "1m");
+        assert_eq!(format(Duration::from_nanos(3_599_999_600_000), style), "1h");
+        assert_eq!(
+            format(Duration::from_nanos(86_399_999_999_999), style),
+            "1d"
+        );
+        assert_eq!(
+            format(
+                Duration::from_nanos(119_999_600_000),
+                FormatStyle::Largest(3)
+            ),
+            "2m"
+        );
+    }
+
+    #[test]
+    fn largest_style() {
+        let duration = Duration::new(93928, 30_000_000); // 1d 2h 5m 28.03s
+        assert_eq!(format(duration, FormatStyle::Largest(0)), "1d");
+        assert_eq!(format(duration, FormatStyle::Largest(1)), "1d");
+        assert_eq!(format(duration, FormatStyle::Largest(2)), "1d 2h");
+        assert_eq!(format(duration, FormatStyle::Largest(3)), "1d 2h 5m");
+        assert_eq!(format(duration, FormatStyle::Largest(4)), "1d 2h 5m 28.03s");
+        assert_eq!(format(duration, FormatStyle::Largest(9)), "1d 2h 5m 28.03s");
+
+        // Zero components within the range are omitted.
+        let duration = Duration::from_secs(86400 + 300); // 1d 0h 5m 0s
+        assert_eq!(format(duration, FormatStyle::Largest(2)), "1d");
+        assert_eq!(format(duration, FormatStyle::Largest(3)), "1d 5m");
+        assert_eq!(format(duration, FormatStyle::Largest(4)), "1d 5m");
+
+        assert_eq!(
+            format(Duration::new(65, 12_345), FormatStyle::Largest(2)),
+            "1m 5s"
+        );
+        assert_eq!(
+            format(Duration::new(0, 80_057), FormatStyle::Largest(2)),
+            "0.000080057s"
+        );
+        assert_eq!(format(Duration::ZERO, FormatStyle::Largest(2)), "0s");
+    }
+
+    #[test]
+    fn natural_style() {
+        let style = FormatStyle::Natural;
+        assert_eq!(format(Duration::ZERO, style), "0 seconds");
+        assert_eq!(format(Duration::new(0, 1), style), "1 nanosecond");
+        assert_eq!(format(Duration::new(0, 57), style), "57 nanoseconds");
+        assert_eq!(
+            format(Duration::new(0, 80_057), style),
+            "80.057 microseconds"
+        );
+        assert_eq!(
+            format(Duration::new(0, 15_200_000), style),
+            "15.2 milliseconds"
+        );
+        assert_eq!(format(Duration::new(1, 0), style), "1 second");
+        assert_eq!(format(Duration::new(1, 500_000), style), "1.001 seconds");
+        assert_eq!(format(Duration::new(90, 0), style), "1.5 minutes");
+        assert_eq!(
+            format(Duration::new(3700, 56_891_730), style),
+            "1.028 hours"
+        );
+        assert_eq!(format(Duration::from_secs(14 * 86400), style), "2 weeks");
+        assert_eq!(
+            format(Duration::from_secs(400 * 86400), style),
+            "1.096 years"
+        );
+
+        // Rounding up carries into the next unit.
+        assert_eq!(
+            format(Duration::new(0, 999_499), style),
+            "999.499 microseconds"
+        );
+        assert_eq!(format(Duration::new(0, 999_999), style), "1 millisecond");
+        assert_eq!(format(Duration::new(0, 999_999_600), style), "1 second");
+    }
+
+    #[test]
+    fn elapsed_time_with_style() {
+        let clock = ManualClock::new();
+        let timer = ExecutionTime::start_with_clock(clock.clone());
+        clock.advance(Duration::new(3700, 56_891_730));
+
+        assert_eq!(
+            timer.get_elapsed_time_with(FormatStyle::Verbose),
+            "1 hour, 1 minute, 40.057 seconds (3700.05689173s)"
+        );
+        assert_eq!(
+            timer.get_elapsed_time_with(FormatStyle::Compact),
+            "1h 1m 40.057s"
+        );
+        assert_eq!(
+            timer.get_elapsed_time_with(FormatStyle::Clock),
+            "01:01:40.057"
+        );
+        assert_eq!(timer.get_elapsed_time_with(FormatStyle::Largest(1)), "1h");
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    decimal::Decimal,
+    exact_time::{duration_from_nanos, trim_fraction},
+    ExactTime, ParseTimeError,
+    ParseTimeErrorKind::{self, *},
+};
+use std::time::Duration;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Formats a time duration as an ISO 8601 duration, such as "P1DT2H5M28.03S".
+///
+/// Zero components are omitted; a zero duration is formatted as "PT0S".
+pub(crate) fn format_iso8601(exact: &ExactTime) -> String {
+    let mut text = String::from("P");
+
+    if exact.days() > 0 {
+        text += &format!("{}D", exact.days());
+    }
+
+    let mut time = String::new();
+    if exact.hours() > 0 {
+        time += &format!("{}H", exact.hours());
+    }
+    if exact.minutes() > 0 {
+        time += &format!("{}M", exact.minutes());
+    }
+    if exact.seconds() > 0 || exact.nanos() > 0 || (time.is_empty() && exact.days() == 0) {
+        time += &trim_fraction(exact.format_seconds(9));
+        time += "S";
+    }
+
+    if !time.is_empty() {
+        text += "T";
+        text += &time;
+    }
+
+    text
+}
+
+/// Parses an ISO 8601 duration, such as "P1DT2H5M28.03S".
+///
+/// Weeks (`W`), days (`D`), hours (`H`), minutes (`M` after `T`) and seconds
+/// (`S`) are supported. Years and months are rejected, because their length
+/// depends on the calendar. Only the last component may have a fractional
+/// part, introduced by a dot or a comma.
+pub(crate) fn parse_iso8601(text: &str) -> Result<Duration, ParseTimeError> {
+    if text.is_empty() {
+        return fail(Empty, "", 0);
+    }
+    if !text.starts_with('P') {
+        return fail(UnexpectedCharacter, first_char(text), 0);
+    }
+
+    let mut position: usize = 1;
+    let mut in_time_part: bool = false;
+    let mut time_designator: Option<usize> = None;
+    let mut last_rank: Option<usize> = None;
+    let mut fraction: Option<(usize, usize)> = None;
+    let mut total: u128 = 0;
+
+    while position < text.len() {
+        let rest: &str = &text[position..];
+
+        if rest.starts_with('T') {
+            if in_time_part {
+                return fail(UnexpectedCharacter, "T", position);
+            }
+            in_time_part = true;
+            time_designator = Some(position);
+            position += 1;
+            continue;
+        }
+
+        let Some((number, length)) = Decimal::read(rest, &['.', ',']) else {
+            return fail(UnexpectedCharacter, first_char(rest), position);
+        };
+
+        // Only the smallest (last) component may have a fraction.
+        if let Some((start, end)) = fraction {
+            return fail(MisplacedFraction, &text[start..end], start);
+        }
+
+        let designator_position: usize = position + length;
+        let designator: &str = first_char(&text[designator_position..]);
+
+        let (rank, unit_seconds): (usize, u128) = match (in_time_part, designator) {
+            (_, "") => return fail(MissingUnit, &text[position..designator_position], position),
+            (false, "Y" | "M") => return fail(UnsupportedUnit, designator, designator_position),
+            (false, "W") => (0, 7 * 86400),
+            (false, "D") => (1, 86400),
+            (true, "H") => (2, 3600),
+            (true, "M") => (3, 60),
+            (true, "S") => (4, 1),
+            (_, other) if other.chars().all(char::is_alphabetic) => {
+                return fail(UnknownUnit, designator, designator_position)
+            }
+            _ => return fail(UnexpectedCharacter, designator, designator_position),
+        };
+
+        if last_rank.is_some_and(|last| last >= rank) {
+            return fail(UnitOrder, designator, designator_position);
+        }
+        last_rank = Some(rank);
+
+        if !number.is_integer() {
+            fraction = Some((position, designator_position));
+        }
+
+        total = number
+            .to_nanos(unit_seconds * NANOS_PER_SECOND)
+            .and_then(|nanos| total.checked_add(nanos))
+            .map_or_else(
+                || fail(Overflow, &text[position..=designator_position], position),
+                Ok,
+            )?;
+
+        position = designator_position + designator.len();
+    }
+
+    match (last_rank, time_designator) {
+        // "P" alone: no component at all.
+        (None, None) => return fail(MissingUnit, "", position),
+        // "P1DT": a time designator without time components.
+        (_, Some(t)) if last_rank.map_or(true, |rank| rank < 2) => {
+            return fail(UnexpectedCharacter, "T", t)
+        }
+        _ => {}
+    }
+
+    duration_from_nanos(total).map_or_else(|| fail(Overflow, text, 0), Ok)
+}
+
+/// Builds the error result of a parser.
+pub(crate) fn fail<T>(
+    kind: ParseTimeErrorKind,
+    token: &str,
+    position: usize,
+) -> Result<T, ParseTimeError> {
+    Err(ParseTimeError::new(kind, token, position))
+}
+
+/// Gets the first character of the text, or an empty string.
+pub(crate) fn first_char(text: &str) -> &str {
+    text.chars().next().map_or("", |c| &text[..c.len_utf8()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(duration: Duration) -> String {
+        format_iso8601(&ExactTime::from(duration))
+    }
+
+    #[test]
+    fn format_durations() {
+        assert_eq!(format(Duration::new(93928, 30_000_000)), "P1DT2H5M28.03S");
+        assert_eq!(format(Duration::from_secs(86400)), "P1D");
+        assert_eq!(format(Duration::from_secs(3600)), "PT1H");
+        assert_eq!(format(Duration::new(0, 57)), "PT0.000000057S");
+        assert_eq!(format(Duration::ZERO), "PT0S");
+    }
+
+    #[test]
+    fn parse_durations() {
+        let parse = |text: &str| parse_iso8601(text).unwrap();
+
+        assert_eq!(parse("P1DT2H5M28.03S"), Duration::new(93928, 30_000_000));
+        assert_eq!(parse("PT0S"), Duration::ZERO);
+        assert_eq!(parse("P2W"), Duration::from_secs(14 * 86400));
+        assert_eq!(parse("PT1,5H"), Duration::from_secs(5400));
+        assert_eq!(parse("PT90M"), Duration::from_secs(5400));
+        assert_eq!(parse("PT0.000000001S"), Duration::new(0, 1));
+        assert_eq!(parse("P1D"), Duration::from_secs(86400));
+    }
+
+    #[test]
+    fn round_trip() {
+        for duration in [
+            Duration::ZERO,
+            Duration::new(0, 1),
+            Duration::new(93928, 30_000_000),
+            Duration::new(946_080_000, 123_456_789),
+            Duration::MAX,
+        ] {
+            assert_eq!(parse_iso8601(&format(duration)), Ok(duration));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text: &str| {
+            let error = parse_iso8601(text).unwrap_err();
+            (error.kind(), error.token().to_string(), error.position())
+        };
+        let expect = |kind, token: &str, position| (kind, token.to_string(), position);
+
+        assert_eq!(error(""), expect(Empty, "", 0));
+        assert_eq!(error("1D"), expect(UnexpectedCharacter, "1", 0));
+        assert_eq!(error("P"), expect(MissingUnit, "", 1));
+        assert_eq!(error("P1DT"), expect(UnexpectedCharacter, "T", 3));
+        assert_eq!(error("PT5"), expect(MissingUnit, "5", 2));
+        assert_eq!(error("P1Y"), expect(UnsupportedUnit, "Y", 2));
+        assert_eq!(error("P1M"), expect(UnsupportedUnit, "M", 2));
+        assert_eq!(error("PT1X"), expect(UnknownUnit, "X", 3));
+        assert_eq!(error("PT1H?"), expect(UnexpectedCharacter, "?", 4));
+        assert_eq!(error("PT1S2M"), expect(UnitOrder, "M", 5));
+        assert_eq!(error("PT1H1H"), expect(UnitOrder, "H", 5));
+        assert_eq!(error("PT1D"), expect(UnknownUnit, "D", 3));
+        assert_eq!(error("PT1.5H2M"), expect(MisplacedFraction, "1.5", 2));
+        assert_eq!(error("PTT1S"), expect(UnexpectedCharacter, "T", 2));
+        assert_eq!(
+            error("P9999999999999999999999999D"),
+            expect(Overflow, "9999999999999999999999999D", 1)
+        );
+        assert_eq!(
+            error("P999999999999999D"),
+            expect(Overflow, "P999999999999999D", 0)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::DurationExtension;
+use std::time::Duration;
+
+/// A named split point recorded by [`ExecutionTime::lap`](crate::ExecutionTime::lap).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lap {
+    /// The name given to the lap.
+    pub name: String,
+    /// Time elapsed since the previous lap (or since the start, for the first lap).
+    pub split: Duration,
+    /// Time elapsed since the start of the timer.
+    pub cumulative: Duration,
+}
+
+impl Lap {
+    /// Returns the share of `total` taken by this lap's split, as a percentage.
+    ///
+    /// Returns `0.0` if `total` is zero.
+    pub fn percentage_of(&self, total: Duration) -> f64 {
+        if total.is_zero() {
+            return 0.0;
+        }
+        100.0 * self.split.as_secs_f64() / total.as_secs_f64()
+    }
+}
+
+/// Renders a table with one row per lap: name, split time, share of the total
+/// and cumulative time.
+///
+/// The total is the cumulative time of the last lap, so the shares add up to 100%.
+pub(crate) fn format_laps(laps: &[Lap]) -> String {
+    let total: Duration = laps.last().map(|lap| lap.cumulative).unwrap_or_default();
+
+    let rows: Vec<[String; 4]> = laps
+        .iter()
+        .map(|lap| {
+            [
+                lap.name.clone(),
+                lap.split.get_time().format_time(),
+                format!("{:.2}%", lap.percentage_of(total)),
+                lap.cumulative.get_time().format_time(),
+            ]
+        })
+        .collect();
+
+    let header = ["Lap", "Split", "Share", "Cumulative"].map(String::from);
+
+    // Width of each column: the longest cell, header included.
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            format!(
+                "{:<w0$}  {:<w1$}  {:>w2$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn laps() -> Vec<Lap> {
+        vec![
+            Lap {
+                name: "load".to_string(),
+                split: Duration::from_secs(1),
+                cumulative: Duration::from_secs(1),
+            },
+            Lap {
+                name: "process".to_string(),
+                split: Duration::from_secs(63),
+                cumulative: Duration::from_secs(64),
+            },
+            Lap {
+                name: "save".to_string(),
+                split: Duration::from_millis(15_200),
+                cumulative: Duration::from_millis(79_200),
+            },
+        ]
+    }
+
+    #[test]
+    fn lap_percentage() {
+        let laps = laps();
+        let total = Duration::from_secs(80);
+        assert_eq!(laps[0].percentage_of(total), 1.25);
+        assert_eq!(laps[1].percentage_of(total), 78.75);
+        assert_eq!(laps[0].percentage_of(Duration::ZERO), 0.0);
+    }
+
+    #[test]
+    /// `cargo test -- --show-output lap_table`
+    fn lap_table() {
+        let table = format_laps(&laps());
+        println!("{table}");
+
+        let expected = [
+            "Lap      Split                     Share  Cumulative",
+            "load     1.000 seconds             1.26%  1.000 seconds",
+            "process  1 minute, 3.000 seconds  79.55%  1 minute, 4.000 seconds",
+            "save     15.200 seconds           19.19%  1 minute, 19.200 seconds",
+        ]
+        .join("\n");
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn lap_table_empty() {
+        assert_eq!(format_laps(&[]), "Lap  Split  Share  Cumulative");
+    }
+}
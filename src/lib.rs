@@ -1,15 +1,64 @@
+mod bench;
+mod clock;
+mod compare;
+mod cpu_time;
+mod decimal;
+mod elapsed;
+mod error;
+mod exact_time;
+mod format_options;
+mod format_style;
+mod iso8601;
+mod lap;
+mod locale;
+#[cfg(feature = "log")]
+mod logging;
+mod outliers;
+mod parse;
+mod plural;
+mod samples;
+mod scope;
+mod sink;
+mod span;
 mod time;
+mod time_ops;
+#[cfg(feature = "tracing")]
+mod tracing_layer;
 mod traits;
 
-pub use self::{time::*, traits::*};
-use std::time::{Duration, Instant};
+#[cfg(feature = "tracing")]
+pub use self::tracing_layer::*;
+pub use self::{
+    bench::*, clock::*, compare::*, cpu_time::*, elapsed::*, error::*, exact_time::*,
+    format_options::*, format_style::*, lap::*, locale::*, outliers::*, parse::*, plural::*,
+    samples::*, scope::*, sink::*, span::*, time::*, traits::*,
+};
+use std::time::Duration;
 
 /// Measures the execution time of a code block.
 ///
 /// This struct provides methods to start a timer and print the elapsed time
 /// in a user-friendly format.
-pub struct ExecutionTime {
-    start_time: Instant,
+///
+/// The timer can be paused and resumed: only the active intervals are
+/// accumulated, while the wall time since the start remains available
+/// through [`ExecutionTime::get_wall_duration`].
+///
+/// Time is read from a [`Clock`], by default the monotonic [`MonotonicClock`].
+/// Tests can use a [`ManualClock`] instead to control time explicitly.
+pub struct ExecutionTime<C = MonotonicClock> {
+    clock: C,
+    start_time: Duration,
+    /// Start of the current active interval, or `None` while paused.
+    resumed_at: Option<Duration>,
+    /// Sum of the active intervals that have already been closed by `pause()`.
+    accumulated: Duration,
+    /// Split points recorded by `lap()`.
+    laps: Vec<Lap>,
+    /// Destination of `print_elapsed_time()` and `print_laps()`.
+    sink: Sink,
+    /// Text written before the elapsed time.
+    prefix: String,
 }
 
 impl ExecutionTime {
@@ -27,17 +76,89 @@ impl ExecutionTime {
     /// timer.print_elapsed_time();
     /// ```
     pub fn start() -> Self {
+        Self::start_with_clock(MonotonicClock::default())
+    }
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Starts a new stopwatch that reads time from the given clock.
+    pub fn start_with_clock(clock: C) -> Self {
+        let now = clock.now();
         Self {
-            start_time: Instant::now(),
+            clock,
+            start_time: now,
+            resumed_at: Some(now),
+            accumulated: Duration::ZERO,
+            laps: Vec::new(),
+            sink: Sink::default(),
+            prefix: sink::DEFAULT_PREFIX.to_string(),
         }
     }
 
-    /// Gets the elapsed time as a `Duration`.
+    /// Gets the time elapsed on the clock since the given reading.
+    fn since(&self, reading: Duration) -> Duration {
+        self.clock.now().saturating_sub(reading)
+    }
+
+    /// Pauses the stopwatch.
+    ///
+    /// The time spent while paused is not included in the active time reported
+    /// by [`ExecutionTime::get_duration`]. Calling this method on a paused timer
+    /// has no effect.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// let mut timer = ExecutionTime::start();
+    /// // ... code to measure ...
+    /// timer.pause();
+    /// // ... setup or I/O that should not be measured ...
+    /// timer.resume();
+    /// // ... more code to measure ...
+    /// timer.print_elapsed_time();
+    /// ```
+    pub fn pause(&mut self) {
+        if let Some(resumed_at) = self.resumed_at.take() {
+            self.accumulated += self.since(resumed_at);
+        }
+    }
+
+    /// Resumes a paused stopwatch.
+    ///
+    /// Calling this method on a running timer has no effect.
+    pub fn resume(&mut self) {
+        if self.resumed_at.is_none() {
+            self.resumed_at = Some(self.clock.now());
+        }
+    }
+
+    /// Returns `true` if the stopwatch is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.resumed_at.is_none()
+    }
+
+    /// Gets the active elapsed time as a `Duration`.
     ///
     /// `Duration` represents a span of time composed of whole seconds
     /// and a fractional part represented in nanoseconds.
+    ///
+    /// Intervals during which the timer was paused are excluded.
     pub fn get_duration(&self) -> Duration {
-        self.start_time.elapsed()
+        let running = self
+            .resumed_at
+            .map(|resumed_at| self.since(resumed_at))
+            .unwrap_or_default();
+        self.accumulated + running
+    }
+
+    /// Gets the wall time since the timer was started as a `Duration`.
+    ///
+    /// Unlike [`ExecutionTime::get_duration`], this includes the intervals
+    /// during which the timer was paused.
+    pub fn get_wall_duration(&self) -> Duration {
+        self.since(self.start_time)
     }
 
     /// Gets the elapsed time as a `Time` struct.
@@ -53,16 +174,72 @@ impl ExecutionTime {
     /// This method calculates the elapsed time, formats it into a readable string,
     /// and includes both the formatted time and the raw `Duration` for debugging.
     pub fn get_elapsed_time(&self) -> String {
-        let duration: Duration = self.get_duration();
-        let time: Time = duration.get_time();
-        format!("{} ({duration:?})", time.format_time())
+        self.elapsed().to_string()
     }
 
     /// Prints the time elapsed since the timer was started to the console.
     ///
-    /// This method prints the formatted elapsed time to `stdout`.
+    /// This method prints the prefix ("Elapsed time: " by default) and the
+    /// formatted elapsed time to the sink, `stdout` by default.
+    /// See [`ExecutionTime::with_sink`] and [`ExecutionTime::with_prefix`].
     pub fn print_elapsed_time(&self) {
-        println!("Elapsed time: {}", self.get_elapsed_time());
+        // Reporting is best effort: write errors, such as a closed pipe, are ignored.
+        let _ = self.sink.write_line(&self.format_elapsed_message());
+    }
+
+    /// Records a named split point.
+    ///
+    /// The split is the active time elapsed since the previous lap (or since
+    /// the start, for the first lap), and the cumulative time is the active
+    /// time elapsed since the start.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// let mut timer = ExecutionTime::start();
+    /// // ... load the data ...
+    /// timer.lap("load");
+    /// // ... process the data ...
+    /// let lap = timer.lap("process");
+    /// assert!(lap.cumulative >= lap.split);
+    ///
+    /// timer.print_laps();
+    /// ```
+    pub fn lap(&mut self, name: impl Into<String>) -> &Lap {
+        let cumulative: Duration = self.get_duration();
+        let previous: Duration = self
+            .laps
+            .last()
+            .map(|lap| lap.cumulative)
+            .unwrap_or_default();
+
+        self.laps.push(Lap {
+            name: name.into(),
+            split: cumulative.saturating_sub(previous),
+            cumulative,
+        });
+
+        self.laps.last().expect("a lap has just been recorded")
+    }
+
+    /// Gets the split points recorded so far.
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    /// Formats the recorded laps as a table.
+    ///
+    /// Each row shows the lap name, the split time, its percentage of the total
+    /// and the cumulative time. The total is the cumulative time of the last lap.
+    pub fn format_laps(&self) -> String {
+        format_laps(&self.laps)
+    }
+
+    /// Prints the table of recorded laps to the sink, `stdout` by default.
+    pub fn print_laps(&self) {
+        let _ = self.sink.write_line(&self.format_laps());
     }
 }
 
@@ -79,6 +256,71 @@ mod tests {
         assert!(elapsed >= Duration::from_nanos(45)); // Allow some margin
     }
 
+    #[test]
+    fn pause_and_resume() {
+        let clock = ManualClock::new();
+        let mut timer = ExecutionTime::start_with_clock(clock.clone());
+        assert!(!timer.is_paused());
+
+        clock.advance(Duration::from_secs(3));
+        timer.pause();
+        assert!(timer.is_paused());
+
+        // The active time does not advance while paused, but the wall time does.
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(timer.get_duration(), Duration::from_secs(3));
+        assert_eq!(timer.get_wall_duration(), Duration::from_secs(13));
+
+        // Pausing twice is a no-op.
+        timer.pause();
+        assert_eq!(timer.get_duration(), Duration::from_secs(3));
+
+        timer.resume();
+        assert!(!timer.is_paused());
+        clock.advance(Duration::from_millis(250));
+
+        // Resuming a running timer is a no-op.
+        timer.resume();
+        clock.advance(Duration::from_millis(250));
+
+        assert_eq!(timer.get_duration(), Duration::from_millis(3500));
+        assert_eq!(timer.get_wall_duration(), Duration::from_millis(13500));
+        assert_eq!(timer.get_elapsed_time(), "3.500 seconds (3.5s)".to_string());
+    }
+
+    #[test]
+    fn laps() {
+        let clock = ManualClock::new();
+        let mut timer = ExecutionTime::start_with_clock(clock.clone());
+
+        clock.advance(Duration::from_secs(1));
+        let first = timer.lap("first").clone();
+
+        // Paused intervals are excluded from the splits.
+        timer.pause();
+        clock.advance(Duration::from_secs(60));
+        timer.resume();
+
+        clock.advance(Duration::from_secs(3));
+        let second = timer.lap("second").clone();
+
+        assert_eq!(first.split, Duration::from_secs(1));
+        assert_eq!(first.cumulative, Duration::from_secs(1));
+        assert_eq!(second.split, Duration::from_secs(3));
+        assert_eq!(second.cumulative, Duration::from_secs(4));
+
+        let names: Vec<&str> = timer.laps().iter().map(|lap| lap.name.as_str()).collect();
+        assert_eq!(names, ["first", "second"]);
+
+        let expected = [
+            "Lap     Split           Share  Cumulative",
+            "first   1.000 seconds  25.00%  1.000 seconds",
+            "second  3.000 seconds  75.00%  4.000 seconds",
+        ]
+        .join("\n");
+        assert_eq!(timer.format_laps(), expected);
+    }
+
     #[test]
     /// `cargo test -- --show-output main`
     fn main() -> Result<(), Error> {
@@ -116,7 +358,7 @@ mod tests {
             }
         );
 
-        assert_eq!(formatted_output, "0.000000057 second (57ns)");
+        assert_eq!(formatted_output, "0.000000057 seconds (57ns)");
 
         Ok(())
     }
@@ -143,7 +385,7 @@ mod tests {
             }
         );
 
-        assert_eq!(formatted_output, "0.000080057 second (80.057µs)");
+        assert_eq!(formatted_output, "0.000080057 seconds (80.057µs)");
 
         Ok(())
     }
@@ -170,7 +412,7 @@ mod tests {
             }
         );
 
-        assert_eq!(formatted_output, "0.015200 second (15.2ms)");
+        assert_eq!(formatted_output, "0.015200 seconds (15.2ms)");
 
         Ok(())
     }
@@ -0,0 +1,470 @@
+use crate::{ExactTime, FormatOptions, PluralRule, SingularPlural, Time, Unit};
+use std::{
+    collections::HashMap,
+    fmt, mem,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// The singular and plural forms of a unit word, such as "hora" and "horas".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnitNames {
+    singular: String,
+    plural: String,
+}
+
+impl UnitNames {
+    /// Creates the names of a unit from its singular and plural forms.
+    pub fn new(singular: impl Into<String>, plural: impl Into<String>) -> Self {
+        Self {
+            singular: singular.into(),
+            plural: plural.into(),
+        }
+    }
+}
+
+impl SingularPlural for UnitNames {
+    fn singular(&self) -> &str {
+        &self.singular
+    }
+
+    fn plural(&self) -> &str {
+        &self.plural
+    }
+}
+
+/// The words and punctuation used to format a time duration in a language.
+///
+/// A locale supplies the unit words, the separator between the components,
+/// the conjunction before the last component and the decimal separator.
+/// English, Portuguese, Spanish, French and German are built in; custom
+/// locales are created with [`Locale::new`], filled with any type implementing
+/// [`SingularPlural`] and made available by code with [`Locale::register`].
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{Locale, Time, Unit, UnitNames};
+///
+/// let time = Time { days: 0, hours: 1, minutes: 5, seconds: 2.5 };
+///
+/// let portuguese = Locale::get("pt-BR").unwrap();
+/// assert_eq!(time.format_localized(&portuguese), "1 hora, 5 minutos e 2,500 segundos");
+///
+/// let dutch = Locale::new("nl")
+///     .with_unit(Unit::Second, UnitNames::new("seconde", "seconden"))
+///     .with_unit(Unit::Minute, UnitNames::new("minuut", "minuten"))
+///     .with_unit(Unit::Hour, UnitNames::new("uur", "uur"))
+///     .with_conjunction(" en ")
+///     .with_decimal_separator(',');
+/// Locale::register(dutch);
+///
+/// let dutch = Locale::get("nl").unwrap();
+/// assert_eq!(time.format_localized(&dutch), "1 uur, 5 minuten en 2,500 seconden");
+/// ```
+#[derive(Clone)]
+pub struct Locale {
+    code: String,
+    units: HashMap<Unit, Arc<dyn SingularPlural + Send + Sync>>,
+    separator: String,
+    conjunction: String,
+    decimal_separator: char,
+    plural_rule: PluralRule,
+}
+
+impl Locale {
+    /// Creates a locale with the given code (such as "pt" or "pt-BR").
+    ///
+    /// It starts with the English words and plural rule, ", " as separator,
+    /// " and " as conjunction and '.' as decimal separator, to be replaced
+    /// with the `with_*` methods.
+    pub fn new(code: impl Into<String>) -> Self {
+        let units = Unit::ALL
+            .into_iter()
+            .map(|unit| {
+                let names: Arc<dyn SingularPlural + Send + Sync> = Arc::new(unit);
+                (unit, names)
+            })
+            .collect();
+
+        Self {
+            code: code.into(),
+            units,
+            separator: ", ".to_string(),
+            conjunction: " and ".to_string(),
+            decimal_separator: '.',
+            plural_rule: PluralRule::English,
+        }
+    }
+
+    /// Sets the words of a unit.
+    pub fn with_unit(
+        mut self,
+        unit: Unit,
+        names: impl SingularPlural + Send + Sync + 'static,
+    ) -> Self {
+        self.units.insert(unit, Arc::new(names));
+        self
+    }
+
+    /// Sets the separator between the components, such as ", ".
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the separator before the last component, such as " e ".
+    pub fn with_conjunction(mut self, conjunction: impl Into<String>) -> Self {
+        self.conjunction = conjunction.into();
+        self
+    }
+
+    /// Sets the decimal separator, such as ','.
+    pub fn with_decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets the rule that selects the plural category of the numbers, and
+    /// thus the form of the unit words.
+    pub fn with_plural_rule(mut self, plural_rule: PluralRule) -> Self {
+        self.plural_rule = plural_rule;
+        self
+    }
+
+    /// The English locale: "1 hour, 5 minutes and 2.500 seconds".
+    pub fn english() -> Self {
+        Locale::new("en")
+    }
+
+    /// The Portuguese locale: "1 hora, 5 minutos e 2,500 segundos".
+    pub fn portuguese() -> Self {
+        Locale::new("pt")
+            .with_plural_rule(PluralRule::Portuguese)
+            .with_names(
+                [
+                    "nanossegundo",
+                    "microssegundo",
+                    "milissegundo",
+                    "segundo",
+                    "minuto",
+                    "hora",
+                    "dia",
+                    "semana",
+                    "ano",
+                ],
+                "s",
+            )
+            .with_conjunction(" e ")
+            .with_decimal_separator(',')
+    }
+
+    /// The Spanish locale: "1 hora, 5 minutos y 2,500 segundos".
+    pub fn spanish() -> Self {
+        Locale::new("es")
+            .with_plural_rule(PluralRule::Spanish)
+            .with_names(
+                [
+                    "nanosegundo",
+                    "microsegundo",
+                    "milisegundo",
+                    "segundo",
+                    "minuto",
+                    "hora",
+                    "día",
+                    "semana",
+                    "año",
+                ],
+                "s",
+            )
+            .with_conjunction(" y ")
+            .with_decimal_separator(',')
+    }
+
+    /// The French locale: "1 heure, 5 minutes et 2,500 secondes".
+    pub fn french() -> Self {
+        Locale::new("fr")
+            .with_plural_rule(PluralRule::French)
+            .with_names(
+                [
+                    "nanoseconde",
+                    "microseconde",
+                    "milliseconde",
+                    "seconde",
+                    "minute",
+                    "heure",
+                    "jour",
+                    "semaine",
+                    "an",
+                ],
+                "s",
+            )
+            .with_conjunction(" et ")
+            .with_decimal_separator(',')
+    }
+
+    /// The German locale: "1 Stunde, 5 Minuten und 2,500 Sekunden".
+    pub fn german() -> Self {
+        Locale::new("de")
+            .with_plural_rule(PluralRule::German)
+            .with_unit(
+                Unit::Nanosecond,
+                UnitNames::new("Nanosekunde", "Nanosekunden"),
+            )
+            .with_unit(
+                Unit::Microsecond,
+                UnitNames::new("Mikrosekunde", "Mikrosekunden"),
+            )
+            .with_unit(
+                Unit::Millisecond,
+                UnitNames::new("Millisekunde", "Millisekunden"),
+            )
+            .with_unit(Unit::Second, UnitNames::new("Sekunde", "Sekunden"))
+            .with_unit(Unit::Minute, UnitNames::new("Minute", "Minuten"))
+            .with_unit(Unit::Hour, UnitNames::new("Stunde", "Stunden"))
+            .with_unit(Unit::Day, UnitNames::new("Tag", "Tage"))
+            .with_unit(Unit::Week, UnitNames::new("Woche", "Wochen"))
+            .with_unit(Unit::Year, UnitNames::new("Jahr", "Jahre"))
+            .with_conjunction(" und ")
+            .with_decimal_separator(',')
+    }
+
+    /// Sets the words of all the units (in the order of [`Unit::ALL`]),
+    /// whose plural is formed by appending a suffix.
+    fn with_names(self, singular: [&str; 9], suffix: &str) -> Self {
+        Unit::ALL
+            .into_iter()
+            .zip(singular)
+            .fold(self, |locale, (unit, word)| {
+                locale.with_unit(unit, UnitNames::new(word, format!("{word}{suffix}")))
+            })
+    }
+
+    /// Makes a locale available to [`Locale::get`], replacing any locale
+    /// registered with the same code.
+    pub fn register(locale: Locale) {
+        registry()
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .insert(locale.code.to_lowercase(), Arc::new(locale));
+    }
+
+    /// Gets a registered locale by its code, ignoring case.
+    ///
+    /// A regional code such as "pt-BR" or "es_ES" falls back to its language
+    /// ("pt" or "es") when it is not registered itself.
+    pub fn get(code: &str) -> Option<Arc<Locale>> {
+        let locales = registry().read().unwrap_or_else(|error| error.into_inner());
+        let code: String = code.to_lowercase();
+
+        locales.get(&code).cloned().or_else(|| {
+            let language: &str = code.split(['-', '_']).next()?;
+            locales.get(language).cloned()
+        })
+    }
+
+    /// Gets the code of the locale.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Gets the words of a unit.
+    pub fn unit(&self, unit: Unit) -> &(dyn SingularPlural + Send + Sync) {
+        self.units[&unit].as_ref()
+    }
+
+    /// Gets the separator between the components.
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Gets the separator before the last component.
+    pub fn conjunction(&self) -> &str {
+        &self.conjunction
+    }
+
+    /// Gets the decimal separator.
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Gets the plural rule.
+    pub fn plural_rule(&self) -> PluralRule {
+        self.plural_rule
+    }
+
+    /// Formats a number followed by the word of the unit for its plural category.
+    pub(crate) fn format_unit(&self, number: &str, unit: Unit) -> String {
+        let category = self.plural_rule.category(number);
+        let word: &str = self.unit(unit).for_category(category);
+        let number: String = number.replace('.', &self.decimal_separator.to_string());
+        format!("{number} {word}")
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::english()
+    }
+}
+
+/// Two locales are equal if they have the same code, punctuation, plural rule
+/// and unit words. Custom plural rules are compared by kind only, since
+/// function pointers have no reliable identity.
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+            && self.separator == other.separator
+            && self.conjunction == other.conjunction
+            && self.decimal_separator == other.decimal_separator
+            && mem::discriminant(&self.plural_rule) == mem::discriminant(&other.plural_rule)
+            && Unit::ALL.into_iter().all(|unit| {
+                let (names, other_names) = (self.unit(unit), other.unit(unit));
+                names.singular() == other_names.singular() && names.plural() == other_names.plural()
+            })
+    }
+}
+
+impl Eq for Locale {}
+
+impl fmt::Debug for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Locale")
+            .field("code", &self.code)
+            .field("separator", &self.separator)
+            .field("conjunction", &self.conjunction)
+            .field("decimal_separator", &self.decimal_separator)
+            .field("plural_rule", &self.plural_rule)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The registered locales, by lowercase code, starting with the built-in ones.
+fn registry() -> &'static RwLock<HashMap<String, Arc<Locale>>> {
+    static LOCALES: OnceLock<RwLock<HashMap<String, Arc<Locale>>>> = OnceLock::new();
+
+    LOCALES.get_or_init(|| {
+        let locales = [
+            Locale::english(),
+            Locale::portuguese(),
+            Locale::spanish(),
+            Locale::french(),
+            Locale::german(),
+        ];
+
+        RwLock::new(
+            locales
+                .into_iter()
+                .map(|locale| (locale.code.clone(), Arc::new(locale)))
+                .collect(),
+        )
+    })
+}
+
+impl Time {
+    /// Formats the time duration like [`Time::format_time`], with the words
+    /// and separators of the given locale.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{Locale, Time};
+    ///
+    /// let time = Time { days: 0, hours: 1, minutes: 5, seconds: 28.03 };
+    ///
+    /// assert_eq!(
+    ///     time.format_localized(&Locale::spanish()),
+    ///     "1 hora, 5 minutos y 28,030 segundos"
+    /// );
+    /// ```
+    pub fn format_localized(&self, locale: &Locale) -> String {
+        self.to_exact().format_localized(locale)
+    }
+}
+
+impl ExactTime {
+    /// Formats the time duration with the words and separators of the given locale.
+    ///
+    /// See [`Time::format_localized`].
+    pub fn format_localized(&self, locale: &Locale) -> String {
+        self.format_with_options(&FormatOptions::new().with_locale(locale.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExtension;
+    use std::time::Duration;
+
+    fn format(seconds: u64, nanos: u32, code: &str) -> String {
+        let locale = Locale::get(code).unwrap();
+        Duration::new(seconds, nanos)
+            .get_exact_time()
+            .format_localized(&locale)
+    }
+
+    #[test]
+    fn built_in_locales() {
+        assert_eq!(format(3905, 0, "en"), "1 hour, 5 minutes and 5.000 seconds");
+        assert_eq!(format(3905, 0, "pt"), "1 hora, 5 minutos e 5,000 segundos");
+        assert_eq!(format(3905, 0, "es"), "1 hora, 5 minutos y 5,000 segundos");
+        assert_eq!(
+            format(3905, 0, "fr"),
+            "1 heure, 5 minutes et 5,000 secondes"
+        );
+        assert_eq!(
+            format(3905, 0, "de"),
+            "1 Stunde, 5 Minuten und 5,000 Sekunden"
+        );
+
+        assert_eq!(
+            format(172_800, 0, "es"),
+            "2 días, 0 horas, 0 minutos y 0,0 segundos"
+        );
+        assert_eq!(format(0, 80_057, "pt"), "0,000080057 segundo");
+        assert_eq!(format(65, 0, "de"), "1 Minute und 5,000 Sekunden");
+    }
+
+    #[test]
+    fn lookup_by_code() {
+        assert_eq!(Locale::get("PT-br").unwrap().code(), "pt");
+        assert_eq!(Locale::get("es_ES").unwrap().code(), "es");
+        assert!(Locale::get("xx").is_none());
+        assert_eq!(Locale::default().code(), "en");
+    }
+
+    #[test]
+    fn custom_locale() {
+        /// Unit words computed instead of stored.
+        struct Shout(Unit);
+
+        impl SingularPlural for Shout {
+            fn singular(&self) -> &str {
+                match self.0 {
+                    Unit::Second => "SECOND",
+                    _ => "UNIT",
+                }
+            }
+
+            fn plural(&self) -> &str {
+                match self.0 {
+                    Unit::Second => "SECONDS",
+                    _ => "UNITS",
+                }
+            }
+        }
+
+        Locale::register(
+            Locale::new("en-x-shout")
+                .with_unit(Unit::Second, Shout(Unit::Second))
+                .with_separator(" / ")
+                .with_conjunction(" + "),
+        );
+
+        assert_eq!(format(3, 500_000_000, "en-x-shout"), "3.500 SECONDS");
+        assert_eq!(
+            format(3605, 0, "EN-X-SHOUT"),
+            "1 hour / 0 minutes + 5.000 SECONDS"
+        );
+    }
+}
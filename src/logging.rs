@@ -0,0 +1,252 @@
+use crate::{Clock, ExecutionTime, ScopedTimer, Sink};
+use log::Level;
+use std::time::Duration;
+
+/// The target of the records of [`ExecutionTime::scoped_log`].
+const DEFAULT_TARGET: &str = "execution_time";
+
+impl Sink {
+    /// Creates a sink that emits each report as a `log` record, with the
+    /// given level and target.
+    ///
+    /// The reports of a [`ScopedTimer`] carry the label and the elapsed time
+    /// in nanoseconds as the `label` and `duration_ns` key-values.
+    ///
+    /// Requires the `log` feature.
+    pub fn log(level: Level, target: impl Into<String>) -> Self {
+        Sink::Log {
+            level,
+            target: target.into(),
+        }
+    }
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Emits the elapsed time as a log record, through the `log` facade.
+    ///
+    /// The message is "{label}: {elapsed time}", formatted as in
+    /// [`ExecutionTime::get_elapsed_time`]. The label and the elapsed time in
+    /// nanoseconds are attached as the `label` and `duration_ns` key-values.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    /// use log::Level;
+    ///
+    /// let timer = ExecutionTime::start();
+    /// // ... your code here ...
+    /// timer.log_elapsed_time(Level::Info, "app::import", "import");
+    /// ```
+    pub fn log_elapsed_time(&self, level: Level, target: &str, label: &str) {
+        let message = format!("{label}: {}", self.get_elapsed_time());
+        log_record(level, target, &message, Some((label, self.get_duration())));
+    }
+}
+
+impl ExecutionTime {
+    /// Starts a new [`ScopedTimer`] that logs its report when dropped, with
+    /// the target "execution_time".
+    ///
+    /// This is a shorthand for
+    /// `ExecutionTime::scoped(label).with_sink(Sink::log(level, "execution_time"))`;
+    /// another target can be set with [`ScopedTimer::with_sink`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{ExecutionTime, Sink};
+    /// use log::Level;
+    ///
+    /// fn import() {
+    ///     let _timer = ExecutionTime::scoped_log("import", Level::Debug);
+    ///     // ... your code here ...
+    /// } // Logs "import: <elapsed time>" here.
+    ///
+    /// fn export() {
+    ///     let _timer = ExecutionTime::scoped("export").with_sink(Sink::log(Level::Info, "app"));
+    ///     // ... your code here ...
+    /// }
+    ///
+    /// import();
+    /// export();
+    /// ```
+    pub fn scoped_log(label: impl Into<String>, level: Level) -> ScopedTimer {
+        ExecutionTime::scoped(label).with_sink(Sink::log(level, DEFAULT_TARGET))
+    }
+}
+
+/// Emits a log record, with the label and duration of a measurement as key-values.
+pub(crate) fn log_record(
+    level: Level,
+    target: &str,
+    message: &str,
+    measurement: Option<(&str, Duration)>,
+) {
+    match measurement {
+        Some((label, duration)) => {
+            let duration_ns: u64 = duration.as_nanos().try_into().unwrap_or(u64::MAX);
+            log::log!(
+                target: target,
+                level,
+                label = label,
+                duration_ns = duration_ns;
+                "{message}"
+            );
+        }
+        None => log::log!(target: target, level, "{message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+    use log::{kv::Key, LevelFilter, Log, Metadata, Record};
+    use std::{
+        cell::RefCell,
+        panic::{self, AssertUnwindSafe},
+        sync::Once,
+    };
+
+    /// A record captured by the test logger.
+    #[derive(Debug, PartialEq)]
+    struct Captured {
+        level: Level,
+        target: String,
+        message: String,
+        label: Option<String>,
+        duration_ns: Option<String>,
+    }
+
+    thread_local! {
+        /// Records are kept per thread, so that the tests can run in parallel.
+        static RECORDS: RefCell<Vec<Captured>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct CapturingLogger;
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            let value = |key: &str| {
+                record
+                    .key_values()
+                    .get(Key::from(key))
+                    .map(|value| value.to_string())
+            };
+
+            RECORDS.with_borrow_mut(|records| {
+                records.push(Captured {
+                    level: record.level(),
+                    target: record.target().to_string(),
+                    message: record.args().to_string(),
+                    label: value("label"),
+                    duration_ns: value("duration_ns"),
+                })
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Installs the logger once, and takes the records of the current thread.
+    fn take_records() -> Vec<Captured> {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&CapturingLogger).unwrap();
+            log::set_max_level(LevelFilter::Trace);
+        });
+        RECORDS.with_borrow_mut(std::mem::take)
+    }
+
+    #[test]
+    fn log_elapsed_time() {
+        take_records();
+
+        let clock = ManualClock::new();
+        let timer = ExecutionTime::start_with_clock(clock.clone());
+        clock.advance(Duration::from_millis(1500));
+        timer.log_elapsed_time(Level::Warn, "app::import", "import");
+
+        assert_eq!(
+            take_records(),
+            [Captured {
+                level: Level::Warn,
+                target: "app::import".to_string(),
+                message: "import: 1.500 seconds (1.5s)".to_string(),
+                label: Some("import".to_string()),
+                duration_ns: Some("1500000000".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn scoped_log_on_drop() {
+        take_records();
+
+        {
+            let timer = ExecutionTime::scoped_log("step", Level::Debug);
+            assert_eq!(timer.label(), "step");
+            assert!(take_records().is_empty());
+        }
+
+        let records = take_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, Level::Debug);
+        assert_eq!(records[0].target, "execution_time");
+        assert!(records[0].message.starts_with("step: "));
+        assert_eq!(records[0].label.as_deref(), Some("step"));
+
+        let mut timer = ExecutionTime::scoped_log("silent", Level::Info);
+        timer.disarm();
+        assert!(!timer.is_armed());
+        drop(timer);
+        assert!(take_records().is_empty());
+    }
+
+    #[test]
+    fn scoped_log_on_panic() {
+        take_records();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _timer = ExecutionTime::scoped("doomed").with_sink(Sink::log(Level::Error, "app"));
+            panic!("boom");
+        }));
+        assert!(result.is_err());
+
+        let records = take_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].target, "app");
+        assert!(records[0].message.starts_with("doomed (aborted): "));
+        assert_eq!(records[0].label.as_deref(), Some("doomed"));
+    }
+
+    #[test]
+    fn print_to_log_sink() {
+        take_records();
+
+        let clock = ManualClock::new();
+        let timer =
+            ExecutionTime::start_with_clock(clock.clone()).with_sink(Sink::log(Level::Info, "app"));
+        clock.advance(Duration::from_millis(1500));
+        timer.print_elapsed_time();
+
+        assert_eq!(format!("{:?}", timer.sink()), "Log");
+        assert_eq!(
+            take_records(),
+            [Captured {
+                level: Level::Info,
+                target: "app".to_string(),
+                message: "Elapsed time: 1.500 seconds (1.5s)".to_string(),
+                label: None,
+                duration_ns: None,
+            }]
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::Samples;
+use std::time::Duration;
+
+/// The rule that decides which measurements are outliers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OutlierMethod {
+    /// Tukey's fences: mild beyond 1.5 interquartile ranges outside the first
+    /// or third quartile, severe beyond 3.
+    #[default]
+    Tukey,
+    /// The median absolute deviation (MAD), scaled by 1.4826 to estimate the
+    /// standard deviation: mild beyond 3 scaled MADs from the median, severe
+    /// beyond 6.
+    Mad,
+}
+
+/// The class of an outlier, by side and distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OutlierKind {
+    /// Below the low severe fence: Q1 − 3 IQR, or the median − 6 scaled MADs.
+    LowSevere,
+    /// Below the low mild fence (Q1 − 1.5 IQR, or the median − 3 scaled MADs), but not severe.
+    LowMild,
+    /// Above the high mild fence (Q3 + 1.5 IQR, or the median + 3 scaled MADs), but not severe.
+    HighMild,
+    /// Above the high severe fence: Q3 + 3 IQR, or the median + 6 scaled MADs.
+    HighSevere,
+}
+
+impl OutlierKind {
+    /// All the classes, from the lowest to the highest measurements.
+    pub const ALL: [OutlierKind; 4] = [
+        OutlierKind::LowSevere,
+        OutlierKind::LowMild,
+        OutlierKind::HighMild,
+        OutlierKind::HighSevere,
+    ];
+
+    /// Returns `true` for the severe outliers.
+    pub fn is_severe(&self) -> bool {
+        matches!(self, OutlierKind::LowSevere | OutlierKind::HighSevere)
+    }
+
+    /// Returns `true` for the outliers below the bulk of the measurements.
+    pub fn is_low(&self) -> bool {
+        matches!(self, OutlierKind::LowSevere | OutlierKind::LowMild)
+    }
+
+    /// Gets the name of the class, such as "high mild".
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutlierKind::LowSevere => "low severe",
+            OutlierKind::LowMild => "low mild",
+            OutlierKind::HighMild => "high mild",
+            OutlierKind::HighSevere => "high severe",
+        }
+    }
+}
+
+/// The classification of a set of measurements, created by
+/// [`Samples::outliers`] or [`Samples::outliers_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outliers {
+    kinds: Vec<Option<OutlierKind>>,
+}
+
+impl Outliers {
+    /// Gets the class of each measurement, in the order of
+    /// [`Samples::as_slice`], or `None` for the regular ones.
+    pub fn kinds(&self) -> &[Option<OutlierKind>] {
+        &self.kinds
+    }
+
+    /// Gets the number of outliers.
+    pub fn count(&self) -> usize {
+        self.kinds.iter().flatten().count()
+    }
+
+    /// Gets the number of outliers of the given class.
+    pub fn count_of(&self, kind: OutlierKind) -> usize {
+        self.kinds.iter().flatten().filter(|k| **k == kind).count()
+    }
+
+    /// Returns `true` if no measurement is an outlier.
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Formats a summary, such as
+    /// "Found 3 outliers among 100 measurements (3.00%): 1 low mild, 2 high severe".
+    pub fn format_report(&self) -> String {
+        let (count, total) = (self.count(), self.kinds.len());
+        if count == 0 {
+            return format!("No outliers among {total} measurements");
+        }
+
+        let classes: Vec<String> = OutlierKind::ALL
+            .iter()
+            .map(|kind| (kind, self.count_of(*kind)))
+            .filter(|(_, n)| *n > 0)
+            .map(|(kind, n)| format!("{n} {}", kind.name()))
+            .collect();
+
+        let noun: &str = if count == 1 { "outlier" } else { "outliers" };
+        format!(
+            "Found {count} {noun} among {total} measurements ({:.2}%): {}",
+            100.0 * count as f64 / total as f64,
+            classes.join(", ")
+        )
+    }
+}
+
+/// The limits beyond which a measurement, in nanoseconds, is an outlier.
+struct Fences {
+    low_severe: f64,
+    low_mild: f64,
+    high_mild: f64,
+    high_severe: f64,
+}
+
+impl Fences {
+    fn new(samples: &Samples, method: OutlierMethod) -> Option<Self> {
+        let nanos = |p: f64| samples.percentile(p).map(|d| d.as_nanos() as f64);
+
+        let (low, high, spread, mild, severe) = match method {
+            OutlierMethod::Tukey => {
+                let (q1, q3) = (nanos(25.0)?, nanos(75.0)?);
+                (q1, q3, q3 - q1, 1.5, 3.0)
+            }
+            OutlierMethod::Mad => {
+                let median: Duration = samples.percentile(50.0)?;
+                let deviations: Samples = samples
+                    .as_slice()
+                    .iter()
+                    .map(|&duration| duration.max(median) - duration.min(median))
+                    .collect();
+                let mad: f64 = deviations.percentile(50.0)?.as_nanos() as f64;
+                let median: f64 = median.as_nanos() as f64;
+                (median, median, 1.4826 * mad, 3.0, 6.0)
+            }
+        };
+
+        Some(Self {
+            low_severe: low - severe * spread,
+            low_mild: low - mild * spread,
+            high_mild: high + mild * spread,
+            high_severe: high + severe * spread,
+        })
+    }
+
+    fn classify(&self, duration: Duration) -> Option<OutlierKind> {
+        let x: f64 = duration.as_nanos() as f64;
+        match x {
+            x if x < self.low_severe => Some(OutlierKind::LowSevere),
+            x if x < self.low_mild => Some(OutlierKind::LowMild),
+            x if x > self.high_severe => Some(OutlierKind::HighSevere),
+            x if x > self.high_mild => Some(OutlierKind::HighMild),
+            _ => None,
+        }
+    }
+}
+
+impl Samples {
+    /// Classifies the measurements with Tukey's fences.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{OutlierKind, Samples};
+    /// use std::time::Duration;
+    ///
+    /// let samples: Samples = [10, 11, 12, 13, 14, 15, 16, 17, 18, 100]
+    ///     .into_iter()
+    ///     .map(Duration::from_millis)
+    ///     .collect();
+    ///
+    /// let outliers = samples.outliers();
+    /// assert_eq!(outliers.count_of(OutlierKind::HighSevere), 1);
+    /// assert_eq!(
+    ///     outliers.format_report(),
+    ///     "Found 1 outlier among 10 measurements (10.00%): 1 high severe"
+    /// );
+    ///
+    /// // Statistics with and without the outliers.
+    /// assert_eq!(samples.stats().unwrap().mean, Duration::from_micros(22_600));
+    /// assert_eq!(samples.without_outliers().stats().unwrap().mean, Duration::from_millis(14));
+    /// ```
+    pub fn outliers(&self) -> Outliers {
+        self.outliers_with(OutlierMethod::Tukey)
+    }
+
+    /// Classifies the measurements with the given method.
+    pub fn outliers_with(&self, method: OutlierMethod) -> Outliers {
+        let fences: Option<Fences> = Fences::new(self, method);
+        let kinds = self
+            .as_slice()
+            .iter()
+            .map(|duration| fences.as_ref()?.classify(*duration))
+            .collect();
+        Outliers { kinds }
+    }
+
+    /// Gets the measurements that are not outliers by Tukey's fences.
+    pub fn without_outliers(&self) -> Samples {
+        self.without_outliers_with(OutlierMethod::Tukey)
+    }
+
+    /// Gets the measurements that are not outliers by the given method.
+    pub fn without_outliers_with(&self, method: OutlierMethod) -> Samples {
+        let outliers: Outliers = self.outliers_with(method);
+        self.as_slice()
+            .iter()
+            .zip(outliers.kinds())
+            .filter(|(_, kind)| kind.is_none())
+            .map(|(duration, _)| *duration)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Samples {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    /// Quartiles of 11.75 ms and 17.25 ms, and a median of 14.5 ms.
+    fn spiky() -> Samples {
+        millis(&[14, 1, 10, 11, 100, 12, 13, 15, 16, 27, 17, 18])
+    }
+
+    #[test]
+    fn tukey_fences() {
+        let outliers = spiky().outliers();
+
+        assert_eq!(outliers.kinds()[1], Some(OutlierKind::LowMild));
+        assert_eq!(outliers.kinds()[4], Some(OutlierKind::HighSevere));
+        assert_eq!(outliers.kinds()[9], Some(OutlierKind::HighMild));
+        assert_eq!(outliers.count(), 3);
+        assert_eq!(outliers.count_of(OutlierKind::LowSevere), 0);
+        assert_eq!(
+            outliers.format_report(),
+            "Found 3 outliers among 12 measurements (25.00%): 1 low mild, 1 high mild, 1 high severe"
+        );
+    }
+
+    #[test]
+    fn median_absolute_deviation() {
+        // A MAD of 3 ms: mild beyond 14.5 ± 13.34 ms, severe beyond 14.5 ± 26.69 ms.
+        let outliers = spiky().outliers_with(OutlierMethod::Mad);
+
+        assert_eq!(outliers.kinds()[1], Some(OutlierKind::LowMild));
+        assert_eq!(outliers.kinds()[4], Some(OutlierKind::HighSevere));
+        assert_eq!(outliers.kinds()[9], None);
+        assert_eq!(outliers.count(), 2);
+
+        let filtered = spiky().without_outliers_with(OutlierMethod::Mad);
+        assert_eq!(filtered.len(), 10);
+    }
+
+    #[test]
+    fn stats_without_outliers() {
+        let samples = spiky();
+        let filtered = samples.without_outliers();
+
+        assert_eq!(filtered, millis(&[14, 10, 11, 12, 13, 15, 16, 17, 18]));
+        assert_eq!(filtered.stats().unwrap().max, Duration::from_millis(18));
+        assert_eq!(samples.stats().unwrap().max, Duration::from_millis(100));
+        assert!(filtered.outliers().is_empty());
+    }
+
+    #[test]
+    fn no_outliers() {
+        let outliers = millis(&[5, 5, 5]).outliers();
+        assert!(outliers.is_empty());
+        assert_eq!(outliers.format_report(), "No outliers among 3 measurements");
+
+        assert_eq!(Samples::new().outliers().kinds(), &[]);
+        assert!(Samples::new().without_outliers().is_empty());
+    }
+
+    #[test]
+    fn kinds() {
+        assert!(OutlierKind::LowSevere.is_severe());
+        assert!(!OutlierKind::HighMild.is_severe());
+        assert!(OutlierKind::LowMild.is_low());
+        assert_eq!(OutlierKind::HighSevere.name(), "high severe");
+        assert!(OutlierKind::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
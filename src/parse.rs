@@ -0,0 +1,299 @@
+use crate::{
+    decimal::Decimal,
+    exact_time::duration_from_nanos,
+    iso8601::{fail, first_char, parse_iso8601},
+    DurationExtension, ParseTimeError,
+    ParseTimeErrorKind::*,
+    SingularPlural, Time, Unit,
+};
+use std::{str::FromStr, time::Duration};
+
+/// Units accepted by the parser, from the largest to the smallest, with their
+/// abbreviations.
+///
+/// The full singular and plural words of the [`Unit`]s are accepted as well.
+/// Years are not, see [`YEARS`].
+const UNITS: [(Unit, &[&str]); 8] = [
+    (Unit::Week, &["w", "wk", "wks"]),
+    (Unit::Day, &["d"]),
+    (Unit::Hour, &["h", "hr", "hrs"]),
+    (Unit::Minute, &["m", "min", "mins"]),
+    (Unit::Second, &["s", "sec", "secs"]),
+    (Unit::Millisecond, &["ms", "msec", "msecs"]),
+    (Unit::Microsecond, &["us", "µs", "μs", "usec", "usecs"]),
+    (Unit::Nanosecond, &["ns", "nsec", "nsecs"]),
+];
+
+/// Abbreviations of the years, which are recognized but not supported,
+/// because their length depends on the calendar, as in ISO 8601 durations.
+const YEARS: [&str; 3] = ["y", "yr", "yrs"];
+
+/// Finds the rank (position in `UNITS`) and length of a unit word, ignoring case.
+fn find_unit(word: &str) -> Option<(usize, u128)> {
+    let word: String = word.to_lowercase();
+
+    UNITS
+        .iter()
+        .enumerate()
+        .find(|(_, (unit, abbreviations))| {
+            abbreviations.contains(&word.as_str())
+                || unit.singular() == word
+                || unit.plural() == word
+        })
+        .map(|(rank, (unit, _))| (rank, unit.nanos()))
+}
+
+/// Returns `true` if the word is a year unit, ignoring case.
+fn is_year(word: &str) -> bool {
+    let word: String = word.to_lowercase();
+    YEARS.contains(&word.as_str()) || Unit::Year.singular() == word || Unit::Year.plural() == word
+}
+
+/// Parses a human-readable time duration into a `Duration`.
+///
+/// The accepted input is a sequence of components, each made of a number and
+/// a unit, optionally separated by spaces, commas or "and":
+///
+/// * the output of [`Time::format_time`], such as "1 minute, 5.000 seconds";
+/// * the singular and plural unit words, such as "2 hours and 1 second";
+/// * common abbreviations, such as "1h30m", "250ms", "1.5 hrs" or "10us".
+///
+/// A week is 7 days. Years are rejected with
+/// [`UnsupportedUnit`](crate::ParseTimeErrorKind::UnsupportedUnit), because
+/// their length depends on the calendar.
+///
+/// The units must appear from the largest to the smallest, at most once each.
+/// Input starting with `P` is parsed as an ISO 8601 duration.
+///
+/// ### Errors
+///
+/// Returns a [`ParseTimeError`] with the failing token and its position.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1 minute, 5.000 seconds"), Ok(Duration::from_secs(65)));
+/// assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+///
+/// let error = parse_duration("5 parsecs").unwrap_err();
+/// assert_eq!(error.to_string(), "unknown unit at position 2: \"parsecs\"");
+/// ```
+pub fn parse_duration(text: &str) -> Result<Duration, ParseTimeError> {
+    let start: usize = text.len() - text.trim_start().len();
+    let trimmed: &str = text.trim();
+
+    if trimmed.is_empty() {
+        return fail(Empty, "", 0);
+    }
+    if trimmed.starts_with('P') {
+        return parse_iso8601(trimmed).map_err(|error| {
+            ParseTimeError::new(error.kind(), error.token(), start + error.position())
+        });
+    }
+
+    let mut position: usize = start;
+    let end: usize = start + trimmed.len();
+    let mut last_rank: Option<usize> = None;
+    let mut total: u128 = 0;
+
+    while position < end {
+        let separator: usize = position + spaces(&text[position..end]);
+        position = skip_separators(text, position, end, last_rank.is_some());
+        if position >= end {
+            // A trailing comma or "and" must be followed by a component.
+            if separator < end {
+                let token: &str = text[separator..end].trim_end();
+                return fail(UnexpectedCharacter, first_char(token), separator);
+            }
+            break;
+        }
+
+        let rest: &str = &text[position..end];
+        let Some((number, length)) = Decimal::read(rest, &['.']) else {
+            let word: &str = read_word(rest);
+            if word.is_empty() {
+                return fail(UnexpectedCharacter, first_char(rest), position);
+            }
+            return fail(InvalidNumber, word, position);
+        };
+
+        let number_end: usize = position + length;
+        let unit_position: usize = number_end + spaces(&text[number_end..end]);
+        let word: &str = read_word(&text[unit_position..end]);
+
+        if word.is_empty() {
+            return fail(MissingUnit, &text[position..number_end], position);
+        }
+
+        let Some((rank, unit_nanos)) = find_unit(word) else {
+            let kind = if is_year(word) {
+                UnsupportedUnit
+            } else {
+                UnknownUnit
+            };
+            return fail(kind, word, unit_position);
+        };
+
+        if last_rank.is_some_and(|last| last >= rank) {
+            return fail(UnitOrder, word, unit_position);
+        }
+        last_rank = Some(rank);
+
+        let unit_end: usize = unit_position + word.len();
+        total = number
+            .to_nanos(unit_nanos)
+            .and_then(|nanos| total.checked_add(nanos))
+            .map_or_else(|| fail(Overflow, &text[position..unit_end], position), Ok)?;
+
+        position = unit_end;
+    }
+
+    duration_from_nanos(total).map_or_else(|| fail(Overflow, trimmed, start), Ok)
+}
+
+impl FromStr for Time {
+    type Err = ParseTimeError;
+
+    /// Parses a human-readable time duration; see [`parse_duration`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::Time;
+    ///
+    /// let time: Time = "1 day, 2 hours, 5 minutes, 28.030 seconds".parse()?;
+    /// assert_eq!(time, Time { days: 1, hours: 2, minutes: 5, seconds: 28.03 });
+    /// assert_eq!(time.format_time().parse::<Time>()?, time);
+    /// # Ok::<(), execution_time::ParseTimeError>(())
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_duration(text).map(|duration| duration.get_time())
+    }
+}
+
+/// Skips the whitespace and, between components, the commas and the word "and".
+fn skip_separators(text: &str, mut position: usize, end: usize, after_component: bool) -> usize {
+    loop {
+        position += spaces(&text[position..end]);
+        let rest: &str = &text[position..end];
+
+        if after_component && rest.starts_with(',') {
+            position += 1;
+        } else if after_component && read_word(rest).eq_ignore_ascii_case("and") {
+            position += "and".len();
+        } else {
+            return position;
+        }
+    }
+}
+
+/// Gets the length in bytes of the leading whitespace.
+fn spaces(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Gets the leading alphabetic word of the text.
+fn read_word(text: &str) -> &str {
+    let length: usize = text
+        .char_indices()
+        .find(|(_, c)| !c.is_alphabetic())
+        .map_or(text.len(), |(index, _)| index);
+    &text[..length]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_format_time_output() {
+        for duration in [
+            Duration::ZERO,
+            Duration::new(0, 57),
+            Duration::new(0, 80_057),
+            Duration::new(0, 15_200_000),
+            Duration::new(5, 80_000_000),
+            Duration::new(65, 0),
+            Duration::new(3700, 56_000_000),
+            Duration::new(93928, 30_000_000),
+        ] {
+            let text: String = duration.get_time().format_time();
+            assert_eq!(parse_duration(&text), Ok(duration), "{text}");
+        }
+
+        let text = "1 day, 0 hour, 0 minute, 0.0 second";
+        assert_eq!(parse_duration(text), Ok(Duration::from_secs(86400)));
+    }
+
+    #[test]
+    fn parse_words_and_abbreviations() {
+        let parse = |text: &str| parse_duration(text).unwrap();
+
+        assert_eq!(parse("2 hours and 1 second"), Duration::from_secs(7201));
+        assert_eq!(parse("1h30m"), Duration::from_secs(5400));
+        assert_eq!(parse("1.5 hrs"), Duration::from_secs(5400));
+        assert_eq!(parse("  1 Hour, 30 MINS  "), Duration::from_secs(5400));
+        assert_eq!(parse("1w 2d"), Duration::from_secs(9 * 86400));
+        assert_eq!(parse("2 weeks"), Duration::from_secs(14 * 86400));
+        assert_eq!(parse("250ms"), Duration::from_millis(250));
+        assert_eq!(parse("1s 250ms 10us 5ns"), Duration::new(1, 250_010_005));
+        assert_eq!(parse("80.057µs"), Duration::from_nanos(80_057));
+        assert_eq!(parse("3 milliseconds"), Duration::from_millis(3));
+        assert_eq!(parse("PT1H30M"), Duration::from_secs(5400));
+    }
+
+    #[test]
+    fn parse_time() {
+        let time: Time = "1 minute, 5.000 seconds".parse().unwrap();
+        assert_eq!(
+            time,
+            Time {
+                days: 0,
+                hours: 0,
+                minutes: 1,
+                seconds: 5.0,
+            }
+        );
+
+        let time: Time = "90m".parse().unwrap();
+        assert_eq!((time.hours, time.minutes), (1, 30));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text: &str| {
+            let error = parse_duration(text).unwrap_err();
+            (error.kind(), error.token().to_string(), error.position())
+        };
+        let expect = |kind, token: &str, position| (kind, token.to_string(), position);
+
+        assert_eq!(error("   "), expect(Empty, "", 0));
+        assert_eq!(error("5"), expect(MissingUnit, "5", 0));
+        assert_eq!(error("1h 30"), expect(MissingUnit, "30", 3));
+        assert_eq!(error("5 parsecs"), expect(UnknownUnit, "parsecs", 2));
+        assert_eq!(error("1y 2w"), expect(UnsupportedUnit, "y", 1));
+        assert_eq!(error("1 year"), expect(UnsupportedUnit, "year", 2));
+        assert_eq!(error("2 YRS"), expect(UnsupportedUnit, "YRS", 2));
+        assert_eq!(error("hours"), expect(InvalidNumber, "hours", 0));
+        assert_eq!(error("1h, and"), expect(UnexpectedCharacter, ",", 2));
+        assert_eq!(error("1h and "), expect(UnexpectedCharacter, "a", 3));
+        assert_eq!(error("1h; 2m"), expect(UnexpectedCharacter, ";", 2));
+        assert_eq!(error("5s 1m"), expect(UnitOrder, "m", 4));
+        assert_eq!(error("1m 1 minute"), expect(UnitOrder, "minute", 5));
+        assert_eq!(error(", 1s"), expect(UnexpectedCharacter, ",", 0));
+        assert_eq!(error(" PT1X"), expect(UnknownUnit, "X", 4));
+        assert_eq!(
+            error("999999999999999 weeks"),
+            expect(Overflow, "999999999999999 weeks", 0)
+        );
+
+        let message = parse_duration("1 minute, 5 secs, 2 fortnights")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(message, "unknown unit at position 20: \"fortnights\"");
+    }
+}
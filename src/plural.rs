@@ -0,0 +1,186 @@
+/// The plural categories of the Unicode CLDR plural rules.
+///
+/// Each language uses a subset of them: English only distinguishes `One`
+/// and `Other`, while Spanish, French and Portuguese also use `Many` for
+/// round millions ("1 000 000 de secondes").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The operands of the CLDR plural rules, read from a formatted number.
+///
+/// The rules depend on how the number is displayed, not only on its value:
+/// "1 second" but "1.0 seconds" in English.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PluralOperands {
+    /// The absolute value of the number.
+    pub n: f64,
+    /// The integer digits of the number.
+    pub i: u64,
+    /// The number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+    /// The visible fraction digits, with trailing zeros.
+    pub f: u64,
+    /// The visible fraction digits, without trailing zeros.
+    pub t: u64,
+}
+
+impl PluralOperands {
+    /// Reads the operands of a formatted number, such as "2", "0.50" or "1,5".
+    ///
+    /// The fractional part is introduced by a dot or a comma; a leading
+    /// minus sign is ignored.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::PluralOperands;
+    ///
+    /// let operands = PluralOperands::from_formatted("1.50");
+    ///
+    /// assert_eq!(operands.n, 1.5);
+    /// assert_eq!((operands.i, operands.v, operands.f, operands.t), (1, 2, 50, 5));
+    /// ```
+    pub fn from_formatted(number: &str) -> Self {
+        let number: &str = number.trim().trim_start_matches('-');
+        let (integer, fraction) = number.split_once(['.', ',']).unwrap_or((number, ""));
+
+        // Saturate instead of failing: only the low digits matter to the rules.
+        let parse = |digits: &str| -> u64 {
+            match digits {
+                "" => 0,
+                digits => digits.parse().unwrap_or(u64::MAX),
+            }
+        };
+
+        Self {
+            n: format!("{integer}.{fraction}0").parse().unwrap_or(f64::NAN),
+            i: parse(integer),
+            v: fraction.len(),
+            f: parse(fraction),
+            t: parse(fraction.trim_end_matches('0')),
+        }
+    }
+}
+
+/// The rule that selects the plural category of a number in a language.
+#[derive(Debug, Clone, Copy)]
+pub enum PluralRule {
+    /// English: `One` for 1 without visible fraction ("1 second", "0 seconds", "1.0 seconds").
+    English,
+    /// German: like English ("1 Sekunde", "1,0 Sekunden").
+    German,
+    /// French: `One` for an integer part of 0 or 1 ("0,5 seconde", "1,9 seconde").
+    French,
+    /// Brazilian Portuguese: like French ("0 segundo", "1,5 segundo").
+    Portuguese,
+    /// Spanish: `One` for the value 1 ("1 segundo", "1,0 segundo", "0 segundos").
+    Spanish,
+    /// A custom rule, for the languages that are not built in.
+    Custom(fn(&PluralOperands) -> PluralCategory),
+}
+
+impl PluralRule {
+    /// Selects the plural category of a formatted number.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{PluralCategory, PluralRule};
+    ///
+    /// assert_eq!(PluralRule::English.category("1"), PluralCategory::One);
+    /// assert_eq!(PluralRule::English.category("1.57"), PluralCategory::Other);
+    /// assert_eq!(PluralRule::French.category("1,57"), PluralCategory::One);
+    /// assert_eq!(PluralRule::Spanish.category("0"), PluralCategory::Other);
+    /// ```
+    pub fn category(&self, number: &str) -> PluralCategory {
+        self.category_of(&PluralOperands::from_formatted(number))
+    }
+
+    /// Selects the plural category from the operands of a number.
+    pub fn category_of(&self, operands: &PluralOperands) -> PluralCategory {
+        let PluralOperands { n, i, v, .. } = *operands;
+        // Round millions, such as "1000000", use `Many` in the Romance languages.
+        let million: bool = i != 0 && i % 1_000_000 == 0 && v == 0;
+
+        match self {
+            PluralRule::English | PluralRule::German if i == 1 && v == 0 => PluralCategory::One,
+            PluralRule::French | PluralRule::Portuguese if i <= 1 => PluralCategory::One,
+            PluralRule::Spanish if n == 1.0 => PluralCategory::One,
+            PluralRule::French | PluralRule::Portuguese | PluralRule::Spanish if million => {
+                PluralCategory::Many
+            }
+            PluralRule::Custom(rule) => rule(operands),
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PluralCategory::*;
+
+    #[test]
+    fn read_operands() {
+        let operands = PluralOperands::from_formatted("-12,0300");
+        assert_eq!(operands.n, 12.03);
+        assert_eq!(
+            (operands.i, operands.v, operands.f, operands.t),
+            (12, 4, 300, 3)
+        );
+
+        let operands = PluralOperands::from_formatted("0");
+        assert_eq!((operands.n, operands.i, operands.v), (0.0, 0, 0));
+    }
+
+    #[test]
+    fn built_in_rules() {
+        let categories = |rule: PluralRule| {
+            ["0", "1", "1.0", "1.57", "2", "0.5", "1000000"].map(|number| rule.category(number))
+        };
+
+        assert_eq!(
+            categories(PluralRule::English),
+            [Other, One, Other, Other, Other, Other, Other]
+        );
+        assert_eq!(
+            categories(PluralRule::German),
+            categories(PluralRule::English)
+        );
+        assert_eq!(
+            categories(PluralRule::French),
+            [One, One, One, One, Other, One, Many]
+        );
+        assert_eq!(
+            categories(PluralRule::Portuguese),
+            categories(PluralRule::French)
+        );
+        assert_eq!(
+            categories(PluralRule::Spanish),
+            [Other, One, One, Other, Other, Other, Many]
+        );
+    }
+
+    #[test]
+    fn custom_rule() {
+        // Polish: 1 sekunda, 2-4 sekundy, 5 sekund, 1,5 sekundy.
+        let polish = PluralRule::Custom(|operands| match (operands.i, operands.v) {
+            (1, 0) => One,
+            (i, 0) if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) => Few,
+            (_, 0) => Many,
+            _ => Other,
+        });
+
+        assert_eq!(
+            ["1", "3", "5", "22", "12", "1,5"].map(|number| polish.category(number)),
+            [One, Few, Many, Few, Many, Other]
+        );
+    }
+}
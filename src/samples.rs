@@ -0,0 +1,322 @@
+use crate::{exact_time::duration_from_nanos, DurationExtension, ExecutionTime, FormatStyle};
+use std::time::Duration;
+
+/// A collection of repeated `Duration` measurements.
+///
+/// One-shot readings are noisy; collecting many of them and summarizing them
+/// with [`Samples::stats`] gives a more reliable picture.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::Samples;
+/// use std::time::Duration;
+///
+/// let samples: Samples = [10, 12, 11, 13, 30]
+///     .into_iter()
+///     .map(Duration::from_millis)
+///     .collect();
+///
+/// let stats = samples.stats().unwrap();
+/// assert_eq!(stats.count, 5);
+/// assert_eq!(stats.min, Duration::from_millis(10));
+/// assert_eq!(stats.median, Duration::from_millis(12));
+/// assert_eq!(stats.mean, Duration::from_micros(15_200));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Samples {
+    durations: Vec<Duration>,
+}
+
+impl Samples {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a measurement.
+    pub fn push(&mut self, duration: Duration) {
+        self.durations.push(duration);
+    }
+
+    /// Runs the closure, adds its execution time and returns its result.
+    pub fn measure<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let timer = ExecutionTime::start();
+        let value: T = f();
+        self.push(timer.get_duration());
+        value
+    }
+
+    /// Gets the number of measurements.
+    pub fn len(&self) -> usize {
+        self.durations.len()
+    }
+
+    /// Returns `true` if there are no measurements.
+    pub fn is_empty(&self) -> bool {
+        self.durations.is_empty()
+    }
+
+    /// Gets the measurements, in the order they were added.
+    pub fn as_slice(&self) -> &[Duration] {
+        &self.durations
+    }
+
+    /// Gets the given percentile (0 to 100) of the measurements, interpolating
+    /// linearly between the closest ranks. Returns `None` if there are no
+    /// measurements.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        percentile_of_sorted(&self.sorted(), percentile)
+    }
+
+    /// Computes the summary statistics, or `None` if there are no measurements.
+    pub fn stats(&self) -> Option<Stats> {
+        let sorted: Vec<Duration> = self.sorted();
+        let count: usize = sorted.len();
+        let percentile = |p: f64| percentile_of_sorted(&sorted, p);
+        let (mean, variance) = self.mean_and_variance()?;
+
+        Some(Stats {
+            count,
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+            mean: duration_from_f64_nanos(mean),
+            median: percentile(50.0)?,
+            std_dev: duration_from_f64_nanos(variance.sqrt()),
+            p90: percentile(90.0)?,
+            p99: percentile(99.0)?,
+            p999: percentile(99.9)?,
+        })
+    }
+
+    /// Gets the mean and the sample variance (with Bessel's correction, zero
+    /// for a single sample) of the measurements, in nanoseconds.
+    pub(crate) fn mean_and_variance(&self) -> Option<(f64, f64)> {
+        let count: usize = self.len();
+        if count == 0 {
+            return None;
+        }
+
+        let total: u128 = self.durations.iter().map(Duration::as_nanos).sum();
+        let mean: f64 = total as f64 / count as f64;
+
+        let variance: f64 = if count > 1 {
+            let squares: f64 = self
+                .durations
+                .iter()
+                .map(|duration| (duration.as_nanos() as f64 - mean).powi(2))
+                .sum();
+            squares / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some((mean, variance))
+    }
+
+    fn sorted(&self) -> Vec<Duration> {
+        let mut sorted: Vec<Duration> = self.durations.clone();
+        sorted.sort_unstable();
+        sorted
+    }
+}
+
+impl FromIterator<Duration> for Samples {
+    fn from_iter<I: IntoIterator<Item = Duration>>(iter: I) -> Self {
+        Self {
+            durations: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Duration> for Samples {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, iter: I) {
+        self.durations.extend(iter);
+    }
+}
+
+/// Summary statistics of a set of measurements, computed by [`Samples::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of measurements.
+    pub count: usize,
+    /// The shortest measurement.
+    pub min: Duration,
+    /// The longest measurement.
+    pub max: Duration,
+    /// The arithmetic mean, rounded to the nearest nanosecond.
+    pub mean: Duration,
+    /// The 50th percentile.
+    pub median: Duration,
+    /// The sample standard deviation.
+    pub std_dev: Duration,
+    /// The 90th percentile.
+    pub p90: Duration,
+    /// The 99th percentile.
+    pub p99: Duration,
+    /// The 99.9th percentile.
+    pub p999: Duration,
+}
+
+impl Stats {
+    /// Gets the 50th percentile, the same as the median.
+    pub fn p50(&self) -> Duration {
+        self.median
+    }
+
+    /// Gets the coefficient of variation: the standard deviation relative to
+    /// the mean, as a percentage. It is zero when the mean is zero.
+    pub fn coefficient_of_variation(&self) -> f64 {
+        if self.mean.is_zero() {
+            return 0.0;
+        }
+        100.0 * self.std_dev.as_secs_f64() / self.mean.as_secs_f64()
+    }
+
+    /// Formats the statistics, one per line, with [`Time::format_time`](crate::Time::format_time).
+    pub fn format_report(&self) -> String {
+        self.format_report_with(FormatStyle::Verbose)
+    }
+
+    /// Formats the statistics, one per line, in the given style.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{FormatStyle, Samples};
+    /// use std::time::Duration;
+    ///
+    /// let samples: Samples = (1..=4).map(Duration::from_millis).collect();
+    /// let report = samples.stats().unwrap().format_report_with(FormatStyle::Compact);
+    ///
+    /// assert_eq!(report.lines().next(), Some("Samples: 4"));
+    /// assert_eq!(report.lines().nth(3), Some("Mean: 0.0025s"));
+    /// ```
+    pub fn format_report_with(&self, style: FormatStyle) -> String {
+        let format = |duration: Duration| duration.get_exact_time().format_with(style);
+
+        [
+            format!("Samples: {}", self.count),
+            format!("Min: {}", format(self.min)),
+            format!("Max: {}", format(self.max)),
+            format!("Mean: {}", format(self.mean)),
+            format!("Median: {}", format(self.median)),
+            format!(
+                "Std dev: {} (CV: {:.2}%)",
+                format(self.std_dev),
+                self.coefficient_of_variation()
+            ),
+            format!("p90: {}", format(self.p90)),
+            format!("p99: {}", format(self.p99)),
+            format!("p999: {}", format(self.p999)),
+        ]
+        .join("\n")
+    }
+}
+
+/// Gets a percentile of sorted measurements, interpolating between the closest ranks.
+fn percentile_of_sorted(sorted: &[Duration], percentile: f64) -> Option<Duration> {
+    let last: usize = sorted.len().checked_sub(1)?;
+    let rank: f64 = percentile.clamp(0.0, 100.0) / 100.0 * last as f64;
+
+    let lower: usize = rank.floor() as usize;
+    let upper: usize = rank.ceil() as usize;
+    let fraction: f64 = rank - lower as f64;
+
+    let (low, high) = (sorted[lower].as_nanos(), sorted[upper].as_nanos());
+    let offset: u128 = ((high - low) as f64 * fraction).round() as u128;
+
+    Some(duration_from_nanos(low + offset).unwrap_or(Duration::MAX))
+}
+
+/// Converts a number of nanoseconds into a `Duration`, saturating.
+pub(crate) fn duration_from_f64_nanos(nanos: f64) -> Duration {
+    // Float to integer casts saturate, and NaN becomes zero.
+    duration_from_nanos(nanos.round() as u128).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Samples {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let stats = millis(&[2, 4, 4, 4, 5, 5, 7, 9]).stats().unwrap();
+
+        assert_eq!(stats.count, 8);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.max, Duration::from_millis(9));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.p50(), stats.median);
+        // Sample variance: 32 / 7.
+        assert_eq!(stats.std_dev, Duration::from_nanos(2_138_090));
+        assert!((stats.coefficient_of_variation() - 42.7618).abs() < 1e-3);
+    }
+
+    #[test]
+    fn percentiles() {
+        let samples: Samples = (1..=1000).map(Duration::from_micros).collect();
+        let stats = samples.stats().unwrap();
+
+        assert_eq!(stats.p90, Duration::from_nanos(900_100));
+        assert_eq!(stats.p99, Duration::from_nanos(990_010));
+        assert_eq!(stats.p999, Duration::from_nanos(999_001));
+
+        assert_eq!(samples.percentile(0.0), Some(Duration::from_micros(1)));
+        assert_eq!(samples.percentile(100.0), Some(Duration::from_micros(1000)));
+        assert_eq!(samples.percentile(150.0), Some(Duration::from_micros(1000)));
+    }
+
+    #[test]
+    fn single_and_empty() {
+        let stats = millis(&[7]).stats().unwrap();
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.p999, Duration::from_millis(7));
+        assert_eq!(stats.coefficient_of_variation(), 0.0);
+
+        assert_eq!(Samples::new().stats(), None);
+        assert_eq!(Samples::new().percentile(50.0), None);
+        assert!(Samples::new().is_empty());
+    }
+
+    #[test]
+    fn measure_closures() {
+        let mut samples = Samples::new();
+        let value = samples.measure(|| 6 * 7);
+        samples.extend([Duration::from_secs(1)]);
+
+        assert_eq!(value, 42);
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples.as_slice()[1], Duration::from_secs(1));
+    }
+
+    #[test]
+    /// `cargo test -- --show-output stats_report`
+    fn stats_report() {
+        let stats = millis(&[10, 12, 11, 13, 30]).stats().unwrap();
+        let report = stats.format_report();
+        println!("{report}");
+
+        let expected = [
+            "Samples: 5",
+            "Min: 0.010000 seconds",
+            "Max: 0.030000 seconds",
+            "Mean: 0.015200 seconds",
+            "Median: 0.012000 seconds",
+            "Std dev: 0.008349 seconds (CV: 54.93%)",
+            "p90: 0.023200 seconds",
+            "p99: 0.029320 seconds",
+            "p999: 0.029932 seconds",
+        ]
+        .join("\n");
+        assert_eq!(report, expected);
+
+        let compact = stats.format_report_with(FormatStyle::Compact);
+        assert_eq!(compact.lines().nth(3), Some("Mean: 0.0152s"));
+    }
+}
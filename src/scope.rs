@@ -0,0 +1,186 @@
+use crate::{ExecutionTime, Sink};
+use std::{
+    io::Write,
+    ops::{Deref, DerefMut},
+};
+
+/// A guard that reports the elapsed time when it goes out of scope.
+///
+/// Created by [`ExecutionTime::scoped`]. The report is written on `Drop`, so it
+/// also happens on early returns, on the `?` operator and while unwinding from
+/// a panic, in which case the measurement is marked as aborted.
+///
+/// The guard dereferences to the underlying [`ExecutionTime`], so it can be
+/// paused, resumed or used to record laps.
+pub struct ScopedTimer {
+    label: String,
+    /// The measurement, whose sink receives the report.
+    timer: ExecutionTime,
+    armed: bool,
+}
+
+impl ExecutionTime {
+    /// Starts a new stopwatch that reports automatically when dropped.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// fn work() -> Result<(), std::num::ParseIntError> {
+    ///     let _timer = ExecutionTime::scoped("work");
+    ///     let _value: u32 = "42".parse()?;
+    ///     // ... your code here ...
+    ///     Ok(())
+    /// } // Prints "work: <elapsed time>" here, even on the `?` path.
+    ///
+    /// work().unwrap();
+    /// ```
+    pub fn scoped(label: impl Into<String>) -> ScopedTimer {
+        ScopedTimer {
+            label: label.into(),
+            timer: ExecutionTime::start(),
+            armed: true,
+        }
+    }
+}
+
+impl ScopedTimer {
+    /// Redirects the report to the given writer instead of `stdout`.
+    pub fn with_writer(self, writer: impl Write + Send + 'static) -> Self {
+        self.with_sink(Sink::writer(writer))
+    }
+
+    /// Redirects the report to the given sink instead of `stdout`.
+    pub fn with_sink(mut self, sink: Sink) -> Self {
+        self.timer.sink = sink;
+        self
+    }
+
+    /// Gets the label of the measurement.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Disarms the guard, so that nothing is reported when it is dropped.
+    pub fn disarm(&mut self) {
+        self.armed = false;
+    }
+
+    /// Returns `true` if the guard will report when it is dropped.
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+
+    /// Formats the report line.
+    fn format_report(&self, aborted: bool) -> String {
+        let status = if aborted { " (aborted)" } else { "" };
+        format!("{}{status}: {}", self.label, self.timer.get_elapsed_time())
+    }
+}
+
+impl Deref for ScopedTimer {
+    type Target = ExecutionTime;
+
+    fn deref(&self) -> &ExecutionTime {
+        &self.timer
+    }
+}
+
+impl DerefMut for ScopedTimer {
+    fn deref_mut(&mut self) -> &mut ExecutionTime {
+        &mut self.timer
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        let report = self.format_report(std::thread::panicking());
+        let duration = self.timer.get_duration();
+
+        // Errors cannot be propagated out of `drop`, so they are ignored.
+        let _ = self
+            .timer
+            .sink
+            .write_measurement(&report, &self.label, duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    fn contents(sink: &Sink) -> String {
+        sink.contents().unwrap()
+    }
+
+    #[test]
+    fn reports_on_drop() {
+        let sink = Sink::buffer();
+        {
+            let timer = ExecutionTime::scoped("step").with_sink(sink.clone());
+            assert_eq!(timer.label(), "step");
+            assert!(contents(&sink).is_empty());
+        }
+
+        let output = contents(&sink);
+        assert!(output.starts_with("step: "), "{output}");
+        assert!(output.ends_with(")\n"), "{output}");
+    }
+
+    #[test]
+    fn reports_on_early_return() {
+        fn parse(sink: Sink, text: &str) -> Result<u32, std::num::ParseIntError> {
+            let _timer = ExecutionTime::scoped("parse").with_sink(sink);
+            let value = text.parse()?;
+            Ok(value)
+        }
+
+        let sink = Sink::buffer();
+        assert!(parse(sink.clone(), "not a number").is_err());
+        assert!(contents(&sink).starts_with("parse: "));
+    }
+
+    #[test]
+    fn disarmed_guard_is_silent() {
+        let sink = Sink::buffer();
+        {
+            let mut timer = ExecutionTime::scoped("silent").with_sink(sink.clone());
+            timer.disarm();
+            assert!(!timer.is_armed());
+        }
+        assert!(contents(&sink).is_empty());
+    }
+
+    #[test]
+    fn marks_panics_as_aborted() {
+        let sink = Sink::buffer();
+        let guard_sink = sink.clone();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(move || {
+            let _timer = ExecutionTime::scoped("doomed").with_sink(guard_sink);
+            panic!("boom");
+        }));
+
+        assert!(result.is_err());
+        assert!(contents(&sink).starts_with("doomed (aborted): "));
+    }
+
+    #[test]
+    fn derefs_to_execution_time() {
+        let mut timer = ExecutionTime::scoped("laps").with_writer(io::sink());
+        timer.pause();
+        assert!(timer.is_paused());
+        timer.resume();
+        timer.lap("first");
+        assert_eq!(timer.laps().len(), 1);
+    }
+}
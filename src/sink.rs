@@ -0,0 +1,280 @@
+use crate::{Clock, ExecutionTime};
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+/// The default prefix of the elapsed time message.
+pub(crate) const DEFAULT_PREFIX: &str = "Elapsed time: ";
+
+/// The destination of the reports of [`ExecutionTime::print_elapsed_time`],
+/// [`ExecutionTime::print_laps`] and [`ScopedTimer`](crate::ScopedTimer).
+///
+/// Cloning a sink shares the underlying file, buffer or writer.
+///
+/// The enum is `#[non_exhaustive]`: the `Log` variant only exists with the
+/// `log` feature, which any crate in the dependency graph may enable, so a
+/// `match` on a sink needs a wildcard arm.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{ExecutionTime, Sink};
+///
+/// let sink = Sink::buffer();
+/// let timer = ExecutionTime::start()
+///     .with_sink(sink.clone())
+///     .with_prefix("build took ");
+///
+/// timer.print_elapsed_time();
+/// assert!(sink.contents().unwrap().starts_with("build took "));
+/// ```
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub enum Sink {
+    /// The standard output. Unlike `println!`, a closed output is reported as
+    /// an error rather than a panic.
+    #[default]
+    Stdout,
+    /// The standard error. Unlike `eprintln!`, a closed output is reported as
+    /// an error rather than a panic.
+    Stderr,
+    /// A file, see [`Sink::file`].
+    File(Arc<Mutex<File>>),
+    /// An in-memory buffer, see [`Sink::buffer`].
+    Buffer(Arc<Mutex<Vec<u8>>>),
+    /// Any writer, see [`Sink::writer`].
+    Writer(Arc<Mutex<dyn Write + Send>>),
+    /// The `log` facade, see [`Sink::log`]. Requires the `log` feature.
+    #[cfg(feature = "log")]
+    Log { level: log::Level, target: String },
+}
+
+impl Sink {
+    /// Opens a file in append mode, creating it if needed.
+    pub fn file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Sink::File(Arc::new(Mutex::new(file))))
+    }
+
+    /// Creates an empty in-memory buffer, read with [`Sink::contents`].
+    pub fn buffer() -> Self {
+        Sink::Buffer(Arc::default())
+    }
+
+    /// Wraps any writer.
+    pub fn writer(writer: impl Write + Send + 'static) -> Self {
+        Sink::Writer(Arc::new(Mutex::new(writer)))
+    }
+
+    /// Gets the text written to an in-memory buffer, or `None` for the other sinks.
+    pub fn contents(&self) -> Option<String> {
+        match self {
+            Sink::Buffer(buffer) => Some(String::from_utf8_lossy(&lock(buffer)).into_owned()),
+            _ => None,
+        }
+    }
+
+    /// Writes a line of text.
+    pub fn write_line(&self, text: &str) -> io::Result<()> {
+        match self {
+            Sink::Stdout => write_line(&mut io::stdout().lock(), text)?,
+            Sink::Stderr => write_line(&mut io::stderr().lock(), text)?,
+            Sink::File(file) => write_line(&mut *lock(file), text)?,
+            Sink::Buffer(buffer) => write_line(&mut *lock(buffer), text)?,
+            Sink::Writer(writer) => write_line(&mut *lock(writer), text)?,
+            #[cfg(feature = "log")]
+            Sink::Log { level, target } => crate::logging::log_record(*level, target, text, None),
+        }
+        Ok(())
+    }
+
+    /// Writes the report of a labelled measurement. The `log` sink attaches
+    /// the label and the duration to the record; the others write the text.
+    #[cfg_attr(not(feature = "log"), allow(unused_variables))]
+    pub(crate) fn write_measurement(
+        &self,
+        text: &str,
+        label: &str,
+        duration: Duration,
+    ) -> io::Result<()> {
+        #[cfg(feature = "log")]
+        if let Sink::Log { level, target } = self {
+            crate::logging::log_record(*level, target, text, Some((label, duration)));
+            return Ok(());
+        }
+        self.write_line(text)
+    }
+}
+
+impl fmt::Debug for Sink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Sink::Stdout => "Stdout",
+            Sink::Stderr => "Stderr",
+            Sink::File(_) => "File",
+            Sink::Buffer(_) => "Buffer",
+            Sink::Writer(_) => "Writer",
+            #[cfg(feature = "log")]
+            Sink::Log { .. } => "Log",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Locks a mutex, ignoring the poisoning: a report is still worth writing.
+fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write_line<W: Write + ?Sized>(writer: &mut W, text: &str) -> io::Result<()> {
+    writeln!(writer, "{text}")?;
+    writer.flush()
+}
+
+impl<C: Clock> ExecutionTime<C> {
+    /// Sets the destination of [`ExecutionTime::print_elapsed_time`] and
+    /// [`ExecutionTime::print_laps`] (by default, `stdout`).
+    pub fn with_sink(mut self, sink: Sink) -> Self {
+        self.sink = sink;
+        self
+    }
+
+    /// Sets the text written before the elapsed time (by default, "Elapsed time: ").
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Gets the destination of the reports.
+    pub fn sink(&self) -> &Sink {
+        &self.sink
+    }
+
+    /// Gets the text written before the elapsed time.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Writes the prefix and the elapsed time, followed by a newline, to the given writer.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// let timer = ExecutionTime::start();
+    /// let mut output: Vec<u8> = Vec::new();
+    /// timer.write_elapsed_time(&mut output)?;
+    ///
+    /// assert!(output.starts_with(b"Elapsed time: "));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_elapsed_time<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "{}", self.format_elapsed_message())
+    }
+
+    /// Prints the prefix and the elapsed time to `stderr`, whatever the sink.
+    pub fn eprint_elapsed_time(&self) {
+        let _ = Sink::Stderr.write_line(&self.format_elapsed_message());
+    }
+
+    /// Formats the prefix followed by the elapsed time.
+    pub(crate) fn format_elapsed_message(&self) -> String {
+        format!("{}{}", self.prefix, self.get_elapsed_time())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+
+    fn timer() -> ExecutionTime<ManualClock> {
+        let clock = ManualClock::new();
+        let timer = ExecutionTime::start_with_clock(clock.clone());
+        clock.advance(Duration::from_millis(1500));
+        timer
+    }
+
+    #[test]
+    fn write_to_writer() {
+        let mut output: Vec<u8> = Vec::new();
+        timer().write_elapsed_time(&mut output).unwrap();
+        assert_eq!(output, b"Elapsed time: 1.500 seconds (1.5s)\n");
+
+        let mut output: Vec<u8> = Vec::new();
+        timer()
+            .with_prefix("[build] ")
+            .write_elapsed_time(&mut output)
+            .unwrap();
+        assert_eq!(output, b"[build] 1.500 seconds (1.5s)\n");
+    }
+
+    #[test]
+    fn print_to_buffer() {
+        let sink = Sink::buffer();
+        let mut timer = timer().with_sink(sink.clone()).with_prefix("");
+        timer.print_elapsed_time();
+        timer.lap("all");
+        timer.print_laps();
+
+        let expected = [
+            "1.500 seconds (1.5s)",
+            "Lap  Split            Share  Cumulative",
+            "all  1.500 seconds  100.00%  1.500 seconds",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(sink.contents().unwrap(), expected);
+        assert_eq!(Sink::Stdout.contents(), None);
+    }
+
+    #[test]
+    fn print_to_file() {
+        let path =
+            std::env::temp_dir().join(format!("execution-time-sink-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let sink = Sink::file(&path).unwrap();
+        timer().with_sink(sink.clone()).print_elapsed_time();
+        timer().with_sink(sink).print_elapsed_time();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "Elapsed time: 1.500 seconds (1.5s)\n".repeat(2));
+    }
+
+    #[test]
+    fn print_to_writer() {
+        let buffer = Sink::buffer();
+        let Sink::Buffer(bytes) = buffer.clone() else {
+            unreachable!()
+        };
+
+        /// A writer that forwards to a shared buffer.
+        struct Forward(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Forward {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let timer = timer().with_sink(Sink::writer(Forward(bytes)));
+        assert_eq!(format!("{:?}", timer.sink()), "Writer");
+        timer.print_elapsed_time();
+        assert_eq!(
+            buffer.contents().unwrap(),
+            "Elapsed time: 1.500 seconds (1.5s)\n"
+        );
+    }
+}
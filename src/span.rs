@@ -0,0 +1,305 @@
+use crate::{DurationExtension, ExecutionTime};
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    time::Duration,
+};
+
+thread_local! {
+    /// Spans currently open on this thread, from the outermost to the innermost.
+    static OPEN_SPANS: RefCell<Vec<OpenSpan>> = const { RefCell::new(Vec::new()) };
+
+    /// Root spans already closed on this thread, waiting to be collected.
+    static FINISHED_SPANS: RefCell<Vec<SpanNode>> = const { RefCell::new(Vec::new()) };
+
+    /// Identifier of the next span opened on this thread.
+    static NEXT_SPAN_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// A span that has been opened and not yet closed.
+struct OpenSpan {
+    id: u64,
+    name: String,
+    timer: ExecutionTime,
+    children: Vec<SpanNode>,
+    /// Set when the guard is dropped while spans opened after it are still open.
+    duration: Option<Duration>,
+}
+
+impl OpenSpan {
+    fn close(self) -> SpanNode {
+        SpanNode {
+            name: self.name,
+            duration: self.duration.unwrap_or_else(|| self.timer.get_duration()),
+            children: self.children,
+        }
+    }
+}
+
+/// A closed span: its total duration and the spans nested inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanNode {
+    /// The name given to the span.
+    pub name: String,
+    /// Total time spent in the span, children included.
+    pub duration: Duration,
+    /// Spans opened and closed while this span was the innermost open span.
+    pub children: Vec<SpanNode>,
+}
+
+impl SpanNode {
+    /// Gets the sum of the durations of the direct children.
+    pub fn children_duration(&self) -> Duration {
+        self.children.iter().map(|child| child.duration).sum()
+    }
+
+    /// Gets the time spent in the span itself, that is, its duration minus
+    /// the duration of its children.
+    pub fn self_time(&self) -> Duration {
+        self.duration.saturating_sub(self.children_duration())
+    }
+
+    /// Formats the span and its descendants as an indented tree.
+    ///
+    /// Each line shows the span name, its duration, its share of the parent
+    /// duration and its self time.
+    pub fn format_tree(&self) -> String {
+        let mut lines = Vec::new();
+        self.push_lines(&mut lines, 0, self.duration);
+        lines.join("\n")
+    }
+
+    fn push_lines(&self, lines: &mut Vec<String>, depth: usize, parent: Duration) {
+        let share: f64 = if parent.is_zero() {
+            0.0
+        } else {
+            100.0 * self.duration.as_secs_f64() / parent.as_secs_f64()
+        };
+
+        lines.push(format!(
+            "{:indent$}{}: {} ({share:.2}%, self {})",
+            "",
+            self.name,
+            self.duration.get_time().format_time(),
+            self.self_time().get_time().format_time(),
+            indent = 2 * depth,
+        ));
+
+        for child in &self.children {
+            child.push_lines(lines, depth + 1, self.duration);
+        }
+    }
+}
+
+/// A guard that closes its span when dropped.
+///
+/// Created by [`ExecutionTime::span`]. Spans are tracked per thread, so the
+/// guard cannot be sent to another thread.
+pub struct SpanGuard {
+    /// Identifier of the span in the stack of open spans.
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ExecutionTime {
+    /// Opens a named span on the current thread.
+    ///
+    /// If another span is open on this thread, the new span becomes its child.
+    /// The span is closed when the returned guard is dropped; closed root spans
+    /// are collected with [`ExecutionTime::take_span_trees`]. A guard dropped
+    /// before those of the spans opened after it ends the span's duration, but
+    /// the span keeps them as children until they are closed.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// {
+    ///     let _request = ExecutionTime::span("request");
+    ///     {
+    ///         let _parse = ExecutionTime::span("parse");
+    ///         // ... parse ...
+    ///     }
+    ///     let _query = ExecutionTime::span("query");
+    ///     // ... query ...
+    /// }
+    ///
+    /// for tree in ExecutionTime::take_span_trees() {
+    ///     println!("{}", tree.format_tree());
+    /// }
+    /// ```
+    pub fn span(name: impl Into<String>) -> SpanGuard {
+        let id: u64 = NEXT_SPAN_ID.with(|next| next.replace(next.get() + 1));
+
+        OPEN_SPANS.with_borrow_mut(|spans| {
+            spans.push(OpenSpan {
+                id,
+                name: name.into(),
+                timer: ExecutionTime::start(),
+                children: Vec::new(),
+                duration: None,
+            });
+        });
+
+        SpanGuard {
+            id,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Takes the root spans closed on the current thread since the last call.
+    pub fn take_span_trees() -> Vec<SpanNode> {
+        FINISHED_SPANS.with_borrow_mut(std::mem::take)
+    }
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        OPEN_SPANS.with_borrow_mut(|spans| {
+            if let Some(span) = spans.iter_mut().find(|span| span.id == self.id) {
+                span.duration = Some(span.timer.get_duration());
+            }
+
+            // Spans are closed from the innermost one: a span whose guard is
+            // dropped before those of the spans opened after it (out of order)
+            // stays in the stack until they are closed, so the tree remains consistent.
+            while spans.last().is_some_and(|span| span.duration.is_some()) {
+                let node = spans.pop().expect("stack is not empty").close();
+                match spans.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => FINISHED_SPANS.with_borrow_mut(|finished| finished.push(node)),
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(node: &SpanNode) -> Vec<&str> {
+        node.children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn nested_spans() {
+        {
+            let _root = ExecutionTime::span("root");
+            {
+                let _first = ExecutionTime::span("first");
+                let _inner = ExecutionTime::span("inner");
+                std::thread::sleep(Duration::from_millis(2));
+            }
+            let _second = ExecutionTime::span("second");
+        }
+
+        let trees = ExecutionTime::take_span_trees();
+        assert_eq!(trees.len(), 1);
+
+        let root = &trees[0];
+        assert_eq!(root.name, "root");
+        assert_eq!(names(root), ["first", "second"]);
+        assert_eq!(names(&root.children[0]), ["inner"]);
+        assert!(root.duration >= root.children_duration());
+        assert!(root.children[0].duration >= root.children[0].children[0].duration);
+
+        // Collected trees are removed from the thread-local store.
+        assert!(ExecutionTime::take_span_trees().is_empty());
+    }
+
+    #[test]
+    fn out_of_order_drop() {
+        let root = ExecutionTime::span("root");
+        let child = ExecutionTime::span("child");
+
+        // Dropping the parent first keeps it in the tree until the child is closed.
+        drop(root);
+        assert!(ExecutionTime::take_span_trees().is_empty());
+        drop(child);
+
+        let trees = ExecutionTime::take_span_trees();
+        assert_eq!(trees.len(), 1);
+        assert_eq!(names(&trees[0]), ["child"]);
+    }
+
+    #[test]
+    fn stale_guards_close_only_their_span() {
+        let first = ExecutionTime::span("first");
+        let second = ExecutionTime::span("second");
+        drop(first);
+
+        let third = ExecutionTime::span("third");
+        let fourth = ExecutionTime::span("fourth");
+
+        // Dropping `second` must not close `third` or `fourth`.
+        drop(second);
+        std::thread::sleep(Duration::from_millis(2));
+        drop(fourth);
+        drop(third);
+
+        let trees = ExecutionTime::take_span_trees();
+        assert_eq!(trees.len(), 1);
+
+        let second = &trees[0].children[0];
+        assert_eq!(names(second), ["third"]);
+        assert_eq!(names(&second.children[0]), ["fourth"]);
+        assert!(second.children[0].children[0].duration >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn spans_are_per_thread() {
+        let _root = ExecutionTime::span("main thread");
+
+        std::thread::spawn(|| {
+            drop(ExecutionTime::span("other thread"));
+            let trees = ExecutionTime::take_span_trees();
+            assert_eq!(trees.len(), 1);
+            assert!(trees[0].children.is_empty());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    /// `cargo test -- --show-output span_tree_format`
+    fn span_tree_format() {
+        let leaf = |name: &str, millis: u64| SpanNode {
+            name: name.to_string(),
+            duration: Duration::from_millis(millis),
+            children: Vec::new(),
+        };
+
+        let tree = SpanNode {
+            name: "request".to_string(),
+            duration: Duration::from_secs(2),
+            children: vec![
+                SpanNode {
+                    name: "parse".to_string(),
+                    duration: Duration::from_millis(500),
+                    children: vec![leaf("tokenize", 100)],
+                },
+                leaf("query", 1_000),
+            ],
+        };
+
+        assert_eq!(tree.self_time(), Duration::from_millis(500));
+
+        let output = tree.format_tree();
+        println!("{output}");
+
+        let expected = [
+            "request: 2.000 seconds (100.00%, self 0.500000 seconds)",
+            "  parse: 0.500000 seconds (25.00%, self 0.400000 seconds)",
+            "    tokenize: 0.100000 seconds (20.00%, self 0.100000 seconds)",
+            "  query: 1.000 seconds (50.00%, self 1.000 seconds)",
+        ]
+        .join("\n");
+
+        assert_eq!(output, expected);
+    }
+}
@@ -1,12 +1,19 @@
-use crate::{FormatFloatValue, FormatIntegerValue, Unit};
-
-// Set a small margin of error for floating-point comparisons.
-const EPSILON: f64 = 1e-10;
+use crate::{
+    exact_time::{duration_from_nanos, total_nanos},
+    iso8601::{format_iso8601, parse_iso8601},
+    DurationExtension, ExactTime, ParseTimeError, TimeError,
+};
+use std::{
+    fmt::{self, Alignment, Write},
+    time::Duration,
+};
 
 /// Represents a time duration split into days, hours, minutes, and seconds.
 ///
 /// This struct holds the components of a time duration for formatting and display purposes.
-#[derive(Debug, Default, PartialEq)]
+///
+/// The seconds are stored as an `f64`; see [`ExactTime`] for a lossless representation.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Time {
     pub days: u64,
     pub hours: u8,
@@ -22,65 +29,218 @@ impl Time {
     ///
     /// It includes only non-zero components, except for seconds, which are always included.
     ///
+    /// The output is derived from the exact integer representation of the time
+    /// (see [`ExactTime`]), with the seconds rounded to the nearest nanosecond.
+    ///
     /// ### Returns
     ///
     /// A formatted time string.
     pub fn format_time(&self) -> String {
-        let mut parts = Vec::new();
+        self.to_exact().format_time()
+    }
 
-        // Add days to the output if they are greater than 0.
-        if self.days > 0 {
-            parts.push(self.days.format_unit(Unit::Day));
-        }
+    /// Formats the time duration as an ISO 8601 duration, such as "P1DT2H5M28.03S".
+    ///
+    /// Zero components are omitted and the seconds keep up to nine decimal
+    /// places, without trailing zeros. A zero duration is formatted as "PT0S".
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::Time;
+    ///
+    /// let time = Time { days: 1, hours: 2, minutes: 5, seconds: 28.03 };
+    /// assert_eq!(time.to_iso8601(), "P1DT2H5M28.03S");
+    /// assert_eq!(Time::from_iso8601("P1DT2H5M28.03S"), Ok(time));
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        format_iso8601(&self.to_exact())
+    }
 
-        // Add hours to the output if they are greater than 0, or if days have already been added.
-        if self.hours > 0 || !parts.is_empty() {
-            parts.push(self.hours.format_unit(Unit::Hour));
-        }
+    /// Parses an ISO 8601 duration, such as "P1DT2H5M28.03S" or "PT90M".
+    ///
+    /// Weeks, days, hours, minutes and seconds are supported, and the result
+    /// is normalized like `DurationExtension::get_time`. Only the last
+    /// component may have a fractional part.
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error for malformed input, for years and months (whose
+    /// length depends on the calendar) and for durations that do not fit in
+    /// a `Duration`.
+    pub fn from_iso8601(text: &str) -> Result<Self, ParseTimeError> {
+        parse_iso8601(text).map(|duration| duration.get_time())
+    }
 
-        // Add minutes to the output if they are greater than 0, or if hours or days have already been added.
-        if self.minutes > 0 || !parts.is_empty() {
-            parts.push(self.minutes.format_unit(Unit::Minute));
-        }
+    /// Converts into the lossless `ExactTime` representation.
+    ///
+    /// The seconds are rounded to the nearest nanosecond and components out of
+    /// their usual range are carried into the larger units.
+    pub fn to_exact(&self) -> ExactTime {
+        ExactTime::from(self)
+    }
 
-        // Determine the number of decimal places to use for seconds.
-        let decimal: usize = self.calculate_decimal();
+    /// Creates a `Time` from components that may exceed their usual range.
+    ///
+    /// Overflowing components are carried into the larger units, so that
+    /// 90 minutes become 1 hour and 30 minutes. The seconds are rounded to the
+    /// nearest nanosecond.
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error if the seconds are negative or not finite, or if the
+    /// total is too large to be represented by a `Duration`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::Time;
+    ///
+    /// let time = Time::normalized(0, 25, 90, 75.5)?;
+    /// assert_eq!(time, Time { days: 1, hours: 2, minutes: 31, seconds: 15.5 });
+    /// # Ok::<(), execution_time::TimeError>(())
+    /// ```
+    pub fn normalized(
+        days: u64,
+        hours: u64,
+        minutes: u64,
+        seconds: f64,
+    ) -> Result<Self, TimeError> {
+        check_seconds(seconds)?;
+        let nanos: u128 = total_nanos(days, hours, minutes, seconds);
+        let duration: Duration = duration_from_nanos(nanos).ok_or(TimeError::Overflow)?;
+        Ok(duration.get_time())
+    }
 
-        // Always add seconds to the output.
-        parts.push(self.seconds.format_float_unit(decimal, Unit::Second));
+    /// Converts the time into a `Duration`, validating its components.
+    ///
+    /// The seconds are rounded to the nearest nanosecond.
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error if hours are not below 24, minutes are not below 60,
+    /// seconds are not in the range [0, 60) or not finite, or if the total is
+    /// too large to be represented by a `Duration`.
+    pub fn to_duration(&self) -> Result<Duration, TimeError> {
+        check_seconds(self.seconds)?;
 
-        parts.join(", ")
+        if self.hours >= 24 {
+            return Err(TimeError::HoursOutOfRange(self.hours));
+        }
+        if self.minutes >= 60 {
+            return Err(TimeError::MinutesOutOfRange(self.minutes));
+        }
+        if self.seconds >= 60.0 {
+            return Err(TimeError::SecondsOutOfRange(self.seconds));
+        }
+
+        let nanos: u128 = total_nanos(
+            self.days,
+            u64::from(self.hours),
+            u64::from(self.minutes),
+            self.seconds,
+        );
+        duration_from_nanos(nanos).ok_or(TimeError::Overflow)
+    }
+}
+
+/// Checks that the seconds are finite and non-negative.
+fn check_seconds(seconds: f64) -> Result<(), TimeError> {
+    if !seconds.is_finite() {
+        Err(TimeError::NonFiniteSeconds(seconds))
+    } else if seconds < 0.0 {
+        Err(TimeError::NegativeSeconds(seconds))
+    } else {
+        Ok(())
     }
+}
 
-    /// Calculates the appropriate number of decimal places for displaying seconds.
+impl fmt::Display for Time {
+    /// Formats the time like [`Time::format_time`], honouring the formatter flags:
+    ///
+    /// * `{:.N}` shows the seconds with `N` decimal places (at most 9).
+    /// * `{:#}` uses the compact form, such as "1d 2h 5m 28.03s".
+    /// * Width, fill and alignment (e.g. `{:>30}`) pad the whole text.
     ///
-    /// This function determines the number of decimal places to show for the seconds
-    /// value based on its magnitude. It aims to provide a balance between precision
-    /// and readability.
-    fn calculate_decimal(&self) -> usize {
-        let sec = self.seconds;
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::Time;
+    ///
+    /// let time = Time { days: 0, hours: 0, minutes: 1, seconds: 5.25 };
+    ///
+    /// assert_eq!(format!("{time}"), "1 minute, 5.250 seconds");
+    /// assert_eq!(format!("{time:.1}"), "1 minute, 5.3 seconds");
+    /// assert_eq!(format!("{time:#}"), "1m 5.25s");
+    /// assert_eq!(format!("[{time:>10.0}]"), "[1 minute, 5 seconds]");
+    /// assert_eq!(format!("[{time:>#10}]"), "[  1m 5.25s]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, &self.format_with_flags(f))
+    }
+}
 
-        if sec < EPSILON {
-            // Handles the case where 'sec' is approximately zero. Show one decimal place.
-            1
-        } else if sec >= 1.0 {
-            // If seconds is greater than or equal to 1, show three decimal places.
-            3
-        } else if sec >= 0.001 {
-            // If seconds is greater than or equal to 0.001, show six decimal places.
-            6
+impl Time {
+    /// Formats the time according to the precision and alternate flags of the formatter.
+    pub(crate) fn format_with_flags(&self, f: &fmt::Formatter<'_>) -> String {
+        let exact: ExactTime = self.to_exact();
+        if f.alternate() {
+            exact.format_compact(f.precision())
         } else {
-            // Otherwise, show nine decimal places for higher precision.
-            9
+            exact.format_verbose(f.precision().unwrap_or_else(|| exact.calculate_decimal()))
         }
     }
 }
 
+/// Writes the text honouring the width, fill and alignment of the formatter.
+///
+/// Unlike `Formatter::pad`, the precision is not used to truncate the text.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let length: usize = text.chars().count();
+    let padding: usize = f.width().unwrap_or(0).saturating_sub(length);
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+
+    let fill: char = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        duration.get_time()
+    }
+}
+
+impl TryFrom<Time> for Duration {
+    type Error = TimeError;
+
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        time.to_duration()
+    }
+}
+
+impl TryFrom<&Time> for Duration {
+    type Error = TimeError;
+
+    fn try_from(time: &Time) -> Result<Self, Self::Error> {
+        time.to_duration()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::duration_extension::DurationExtension;
-    use std::time::Duration;
 
     #[test]
     fn times_new() {
@@ -157,7 +317,7 @@ mod tests {
             seconds: 0.0,
         };
 
-        assert_eq!(time.format_time(), "1 day, 0 hour, 0 minute, 0.0 second");
+        assert_eq!(time.format_time(), "1 day, 0 hours, 0 minutes, 0.0 seconds");
 
         let time = Time {
             days: 1,
@@ -165,7 +325,7 @@ mod tests {
             minutes: 0,
             seconds: 0.0,
         };
-        assert_eq!(time.format_time(), "1 day, 2 hours, 0 minute, 0.0 second");
+        assert_eq!(time.format_time(), "1 day, 2 hours, 0 minutes, 0.0 seconds");
     }
 
     #[test]
@@ -179,6 +339,131 @@ mod tests {
         let time_default = Time::default();
 
         assert_eq!(time, time_default);
-        assert_eq!(time.format_time(), "0.0 second");
+        assert_eq!(time.format_time(), "0.0 seconds");
+    }
+
+    #[test]
+    fn times_to_duration() {
+        let time = Time {
+            days: 1,
+            hours: 2,
+            minutes: 5,
+            seconds: 28.03,
+        };
+        let duration = Duration::try_from(&time).unwrap();
+        assert_eq!(duration, Duration::new(93928, 30_000_000));
+        assert_eq!(Time::from(duration), time);
+
+        let duration = Duration::new(3700, 56_891_730);
+        assert_eq!(Duration::try_from(duration.get_time()), Ok(duration));
+    }
+
+    #[test]
+    fn times_to_duration_validation() {
+        let time = |hours: u8, minutes: u8, seconds: f64| Time {
+            days: 0,
+            hours,
+            minutes,
+            seconds,
+        };
+
+        assert_eq!(
+            Duration::try_from(time(24, 0, 0.0)),
+            Err(TimeError::HoursOutOfRange(24))
+        );
+        assert_eq!(
+            Duration::try_from(time(0, 60, 0.0)),
+            Err(TimeError::MinutesOutOfRange(60))
+        );
+        assert_eq!(
+            Duration::try_from(time(0, 0, 60.0)),
+            Err(TimeError::SecondsOutOfRange(60.0))
+        );
+        assert_eq!(
+            Duration::try_from(time(0, 0, -0.5)),
+            Err(TimeError::NegativeSeconds(-0.5))
+        );
+        assert_eq!(
+            Duration::try_from(time(0, 0, f64::INFINITY)),
+            Err(TimeError::NonFiniteSeconds(f64::INFINITY))
+        );
+        assert!(matches!(
+            Duration::try_from(time(0, 0, f64::NAN)),
+            Err(TimeError::NonFiniteSeconds(_))
+        ));
+
+        let huge = Time {
+            days: u64::MAX,
+            ..Time::default()
+        };
+        assert_eq!(Duration::try_from(huge), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    fn times_normalized() {
+        assert_eq!(
+            Time::normalized(0, 0, 90, 0.0),
+            Ok(Time {
+                days: 0,
+                hours: 1,
+                minutes: 30,
+                seconds: 0.0,
+            })
+        );
+
+        assert_eq!(
+            Time::normalized(2, 49, 0, 3661.25),
+            Ok(Time {
+                days: 4,
+                hours: 2,
+                minutes: 1,
+                seconds: 1.25,
+            })
+        );
+
+        assert_eq!(
+            Time::normalized(0, 0, 0, -1.0),
+            Err(TimeError::NegativeSeconds(-1.0))
+        );
+        assert_eq!(
+            Time::normalized(u64::MAX, 0, 0, 0.0),
+            Err(TimeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn times_display() {
+        let time = Time {
+            days: 1,
+            hours: 2,
+            minutes: 5,
+            seconds: 28.03,
+        };
+
+        assert_eq!(time.to_string(), time.format_time());
+        assert_eq!(
+            format!("{time}"),
+            "1 day, 2 hours, 5 minutes, 28.030 seconds"
+        );
+        assert_eq!(
+            format!("{time:.0}"),
+            "1 day, 2 hours, 5 minutes, 28 seconds"
+        );
+        assert_eq!(
+            format!("{time:.9}"),
+            "1 day, 2 hours, 5 minutes, 28.030000000 seconds"
+        );
+        assert_eq!(format!("{time:#}"), "1d 2h 5m 28.03s");
+        assert_eq!(format!("{time:#.3}"), "1d 2h 5m 28.030s");
+
+        let time = Time {
+            seconds: 4.5,
+            ..Time::default()
+        };
+        assert_eq!(format!("[{time:<15}]"), "[4.500 seconds  ]");
+        assert_eq!(format!("[{time:>15}]"), "[  4.500 seconds]");
+        assert_eq!(format!("[{time:*^15}]"), "[*4.500 seconds*]");
+        assert_eq!(format!("[{time:>8.1}]"), "[4.5 seconds]");
+        assert_eq!(format!("[{time:>#8}]"), "[    4.5s]");
     }
 }
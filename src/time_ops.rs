@@ -0,0 +1,211 @@
+use crate::{
+    exact_time::{duration_from_nanos, total_nanos},
+    DurationExtension, Time,
+};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+};
+
+// The operands are converted into nanoseconds, combined with integer arithmetic
+// and converted back, so the results are normalized into days, hours, minutes
+// and seconds the same way as `DurationExtension::get_time`.
+impl Time {
+    /// Gets the total number of nanoseconds, or `None` if the seconds are
+    /// negative or not finite.
+    fn checked_nanos(self) -> Option<u128> {
+        if !self.seconds.is_finite() || self.seconds < 0.0 {
+            return None;
+        }
+        Some(total_nanos(
+            self.days,
+            u64::from(self.hours),
+            u64::from(self.minutes),
+            self.seconds,
+        ))
+    }
+
+    /// Creates a normalized `Time` from nanoseconds, or `None` if it exceeds
+    /// `Duration::MAX`.
+    fn from_nanos(nanos: u128) -> Option<Time> {
+        duration_from_nanos(nanos).map(|duration| duration.get_time())
+    }
+
+    /// Checked addition. Returns `None` if an operand has negative or
+    /// non-finite seconds, or if the result exceeds `Duration::MAX`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::Time;
+    ///
+    /// let a = Time { days: 0, hours: 0, minutes: 40, seconds: 30.0 };
+    /// let b = Time { days: 0, hours: 0, minutes: 20, seconds: 45.5 };
+    ///
+    /// assert_eq!(
+    ///     a.checked_add(b),
+    ///     Some(Time { days: 0, hours: 1, minutes: 1, seconds: 15.5 })
+    /// );
+    /// ```
+    pub fn checked_add(self, rhs: Time) -> Option<Time> {
+        Self::from_nanos(self.checked_nanos()? + rhs.checked_nanos()?)
+    }
+
+    /// Checked subtraction. Returns `None` if an operand has negative or
+    /// non-finite seconds, or if the result would be negative.
+    pub fn checked_sub(self, rhs: Time) -> Option<Time> {
+        Self::from_nanos(self.checked_nanos()?.checked_sub(rhs.checked_nanos()?)?)
+    }
+
+    /// Checked multiplication by a scalar. Returns `None` if the seconds are
+    /// negative or not finite, or if the result exceeds `Duration::MAX`.
+    pub fn checked_mul(self, rhs: u32) -> Option<Time> {
+        Self::from_nanos(self.checked_nanos()?.checked_mul(u128::from(rhs))?)
+    }
+
+    /// Checked division by a scalar, truncated to the nanosecond. Returns
+    /// `None` if `rhs` is zero or if the seconds are negative or not finite.
+    pub fn checked_div(self, rhs: u32) -> Option<Time> {
+        Self::from_nanos(self.checked_nanos()?.checked_div(u128::from(rhs))?)
+    }
+}
+
+impl Add for Time {
+    type Output = Time;
+
+    /// Panics if an operand is invalid or if the result overflows.
+    fn add(self, rhs: Time) -> Time {
+        self.checked_add(rhs)
+            .expect("overflow or invalid operand when adding times")
+    }
+}
+
+impl AddAssign for Time {
+    fn add_assign(&mut self, rhs: Time) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Time {
+    type Output = Time;
+
+    /// Panics if an operand is invalid or if the result would be negative.
+    fn sub(self, rhs: Time) -> Time {
+        self.checked_sub(rhs)
+            .expect("overflow or invalid operand when subtracting times")
+    }
+}
+
+impl SubAssign for Time {
+    fn sub_assign(&mut self, rhs: Time) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u32> for Time {
+    type Output = Time;
+
+    /// Panics if the time is invalid or if the result overflows.
+    fn mul(self, rhs: u32) -> Time {
+        self.checked_mul(rhs)
+            .expect("overflow or invalid operand when multiplying time by scalar")
+    }
+}
+
+impl Div<u32> for Time {
+    type Output = Time;
+
+    /// Panics if `rhs` is zero or if the time is invalid.
+    fn div(self, rhs: u32) -> Time {
+        self.checked_div(rhs)
+            .expect("divide by zero or invalid operand when dividing time by scalar")
+    }
+}
+
+impl Sum for Time {
+    fn sum<I: Iterator<Item = Time>>(iter: I) -> Time {
+        iter.fold(Time::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Time> for Time {
+    fn sum<I: Iterator<Item = &'a Time>>(iter: I) -> Time {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn time(days: u64, hours: u8, minutes: u8, seconds: f64) -> Time {
+        Time {
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    #[test]
+    fn add_and_sub() {
+        let a = time(0, 23, 59, 59.5);
+        let b = time(0, 0, 0, 0.75);
+
+        assert_eq!(a + b, time(1, 0, 0, 0.25));
+        assert_eq!((a + b) - b, a);
+        assert_eq!(a - a, Time::default());
+
+        let mut total = a;
+        total += b;
+        assert_eq!(total, time(1, 0, 0, 0.25));
+        total -= a;
+        assert_eq!(total, b);
+
+        assert_eq!(b.checked_sub(a), None);
+    }
+
+    #[test]
+    fn mul_and_div() {
+        let lap = time(0, 0, 20, 30.0);
+
+        assert_eq!(lap * 3, time(0, 1, 1, 30.0));
+        assert_eq!(lap.checked_mul(0), Some(Time::default()));
+        assert_eq!((lap * 3) / 3, lap);
+        assert_eq!(time(0, 0, 0, 1.0) / 3, time(0, 0, 0, 0.333333333));
+
+        assert_eq!(lap.checked_div(0), None);
+        assert_eq!(Duration::MAX.get_time().checked_mul(2), None);
+    }
+
+    #[test]
+    fn sum() {
+        let phases = [
+            time(0, 0, 0, 45.0),
+            time(0, 0, 30, 20.0),
+            time(0, 23, 30, 0.0),
+        ];
+
+        assert_eq!(phases.iter().sum::<Time>(), time(1, 0, 1, 5.0));
+        assert_eq!(phases.into_iter().sum::<Time>(), time(1, 0, 1, 5.0));
+        assert_eq!(std::iter::empty::<Time>().sum::<Time>(), Time::default());
+    }
+
+    #[test]
+    fn invalid_operands() {
+        let negative = time(0, 0, 0, -1.0);
+        let nan = time(0, 0, 0, f64::NAN);
+        let one = time(0, 0, 0, 1.0);
+
+        assert_eq!(one.checked_add(negative), None);
+        assert_eq!(nan.checked_add(one), None);
+        assert_eq!(negative.checked_mul(2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "when subtracting times")]
+    fn sub_overflow_panics() {
+        let _ = time(0, 0, 0, 1.0) - time(0, 0, 0, 2.0);
+    }
+}
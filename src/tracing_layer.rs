@@ -0,0 +1,420 @@
+use crate::{Clock, DurationExtension, ExecutionTime, MonotonicClock, Sink};
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+use tracing::{
+    field::{self, Field, Visit},
+    span::{Attributes, EnteredSpan, Id, Record},
+    Span, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// The name of the spans opened by [`ExecutionTime::traced`].
+const SPAN_NAME: &str = "execution_time";
+
+/// The target of the events emitted by [`TimingLayer`].
+const EVENT_TARGET: &str = "execution_time";
+
+/// A `tracing_subscriber` layer that measures the busy and idle time of
+/// every span and writes a summary when the span closes.
+///
+/// A span is busy while it is entered and idle otherwise, from its creation
+/// to its close. The summary, such as
+/// "request: busy 1.000 seconds, idle 0.500000 seconds", is formatted with
+/// [`Time::format_time`](crate::Time::format_time) and emitted as an `INFO`
+/// event with the target "execution_time" and the `busy_ns` and `idle_ns`
+/// fields, so it goes through the subscriber like any other event. With
+/// [`TimingLayer::with_sink`], it is written to a [`Sink`] instead. Spans
+/// with a `label` field, such as those opened by [`ExecutionTime::traced`],
+/// are reported under their label.
+///
+/// Requires the `tracing` feature.
+///
+/// ### Examples
+///
+/// ```
+/// use execution_time::{Sink, TimingLayer};
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let sink = Sink::buffer();
+/// let subscriber = tracing_subscriber::registry().with(TimingLayer::new().with_sink(sink.clone()));
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("request").entered();
+///     // ... your code here ...
+/// });
+///
+/// assert!(sink.contents().unwrap().starts_with("request: busy "));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimingLayer<C = MonotonicClock> {
+    clock: C,
+    /// Destination of the summaries, or `None` to emit events.
+    sink: Option<Sink>,
+}
+
+impl TimingLayer {
+    /// Creates a layer that reads time from the monotonic clock.
+    pub fn new() -> Self {
+        Self::with_clock(MonotonicClock::default())
+    }
+}
+
+impl Default for TimingLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> TimingLayer<C> {
+    /// Creates a layer that reads time from the given clock.
+    pub fn with_clock(clock: C) -> Self {
+        Self { clock, sink: None }
+    }
+
+    /// Writes the summaries to the given sink instead of emitting events.
+    pub fn with_sink(mut self, sink: Sink) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+}
+
+/// The timings of a span, stored in its extensions.
+struct Timings {
+    label: Option<String>,
+    busy: Duration,
+    idle: Duration,
+    /// Clock reading of the last enter or exit.
+    last: Duration,
+    /// Number of nested entries of the span.
+    entered: usize,
+}
+
+/// Reads the `label` field of a span.
+struct LabelVisitor<'a>(&'a mut Option<String>);
+
+impl Visit for LabelVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "label" {
+            *self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "label" {
+            *self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl<S, C> Layer<S> for TimingLayer<C>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    C: Clock + Send + Sync + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut label: Option<String> = None;
+        attrs.record(&mut LabelVisitor(&mut label));
+
+        span.extensions_mut().insert(Timings {
+            label,
+            busy: Duration::ZERO,
+            idle: Duration::ZERO,
+            last: self.clock.now(),
+            entered: 0,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timings) = span.extensions_mut().get_mut::<Timings>() {
+                values.record(&mut LabelVisitor(&mut timings.label));
+            }
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timings) = span.extensions_mut().get_mut::<Timings>() {
+                if timings.entered == 0 {
+                    let now: Duration = self.clock.now();
+                    timings.idle += now.saturating_sub(timings.last);
+                    timings.last = now;
+                }
+                timings.entered += 1;
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timings) = span.extensions_mut().get_mut::<Timings>() {
+                timings.entered = timings.entered.saturating_sub(1);
+                if timings.entered == 0 {
+                    let now: Duration = self.clock.now();
+                    timings.busy += now.saturating_sub(timings.last);
+                    timings.last = now;
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timings) = extensions.get::<Timings>() else {
+            return;
+        };
+
+        // The time since the last exit is idle.
+        let busy: Duration = timings.busy;
+        let idle: Duration = timings.idle + self.clock.now().saturating_sub(timings.last);
+        let name: &str = timings.label.as_deref().unwrap_or(span.name());
+
+        let summary = format!(
+            "{name}: busy {}, idle {}",
+            busy.get_time().format_time(),
+            idle.get_time().format_time()
+        );
+        drop(extensions);
+
+        match &self.sink {
+            // Errors cannot be reported from a layer, so they are ignored.
+            Some(sink) => {
+                let _ = sink.write_line(&summary);
+            }
+            None => {
+                let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+                tracing::info!(
+                    target: EVENT_TARGET,
+                    busy_ns = nanos(busy),
+                    idle_ns = nanos(idle),
+                    "{summary}"
+                );
+            }
+        }
+    }
+}
+
+/// A stopwatch that runs inside an entered tracing span.
+///
+/// Created by [`ExecutionTime::traced`]. When dropped, it records the elapsed
+/// time in the `elapsed` field of the span, then exits and closes the span.
+/// It dereferences to the underlying [`ExecutionTime`].
+///
+/// Requires the `tracing` feature.
+pub struct TracedTimer {
+    timer: ExecutionTime,
+    span: EnteredSpan,
+}
+
+impl ExecutionTime {
+    /// Starts a new stopwatch inside a new, entered `INFO` span named
+    /// "execution_time", whose `label` field is the given label.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::ExecutionTime;
+    ///
+    /// fn import() {
+    ///     let _timer = ExecutionTime::traced("import");
+    ///     tracing::info!("importing"); // Recorded inside the span.
+    ///     // ... your code here ...
+    /// } // Records the elapsed time and closes the span here.
+    ///
+    /// import();
+    /// ```
+    pub fn traced(label: impl Into<String>) -> TracedTimer {
+        let label: String = label.into();
+        let span: Span =
+            tracing::info_span!(SPAN_NAME, label = label.as_str(), elapsed = field::Empty);
+
+        TracedTimer {
+            timer: ExecutionTime::start(),
+            span: span.entered(),
+        }
+    }
+}
+
+impl TracedTimer {
+    /// Gets the span of the measurement.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Deref for TracedTimer {
+    type Target = ExecutionTime;
+
+    fn deref(&self) -> &ExecutionTime {
+        &self.timer
+    }
+}
+
+impl DerefMut for TracedTimer {
+    fn deref_mut(&mut self) -> &mut ExecutionTime {
+        &mut self.timer
+    }
+}
+
+impl Drop for TracedTimer {
+    fn drop(&mut self) {
+        let elapsed: String = self.timer.get_elapsed_time();
+        self.span.record("elapsed", elapsed.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn busy_and_idle_time() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+        let layer = TimingLayer::with_clock(clock.clone()).with_sink(sink.clone());
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request");
+            clock.advance(Duration::from_secs(1)); // idle
+
+            {
+                let _entered = span.enter();
+                clock.advance(Duration::from_secs(2)); // busy
+
+                let _nested = span.enter();
+                clock.advance(Duration::from_millis(500)); // still busy
+            }
+
+            clock.advance(Duration::from_millis(250)); // idle
+            span.in_scope(|| clock.advance(Duration::from_millis(250))); // busy
+            clock.advance(Duration::from_millis(500)); // idle
+        });
+
+        assert_eq!(
+            sink.contents().unwrap(),
+            "request: busy 2.750 seconds, idle 1.750 seconds\n"
+        );
+    }
+
+    #[test]
+    fn nested_spans() {
+        let clock = ManualClock::new();
+        let sink = Sink::buffer();
+        let layer = TimingLayer::with_clock(clock.clone()).with_sink(sink.clone());
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _outer = tracing::info_span!("outer").entered();
+            clock.advance(Duration::from_secs(1));
+            {
+                let _inner = tracing::info_span!("inner", label = "parse").entered();
+                clock.advance(Duration::from_secs(2));
+            }
+            clock.advance(Duration::from_secs(1));
+        });
+
+        assert_eq!(
+            sink.contents().unwrap(),
+            [
+                "parse: busy 2.000 seconds, idle 0.0 seconds",
+                "outer: busy 4.000 seconds, idle 0.0 seconds",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    /// A layer that writes to a sink the events, as "target: message field=value",
+    /// and the values recorded on spans, as "record: field=value".
+    struct Capture(Sink);
+
+    /// The fields of an event or a record, appended to a line.
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.push_str(&format!(" {}={value}", field.name()));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push_str(&format!(" {}={value}", field.name()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            match field.name() {
+                "message" => self.0.push_str(&format!("{value:?}")),
+                name => self.0.push_str(&format!(" {name}={value:?}")),
+            }
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for Capture {
+        fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+            let mut fields = Fields(format!("{}: ", event.metadata().target()));
+            event.record(&mut fields);
+            self.0.write_line(&fields.0).unwrap();
+        }
+
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            let mut fields = Fields("record:".to_string());
+            values.record(&mut fields);
+            self.0.write_line(&fields.0).unwrap();
+        }
+    }
+
+    #[test]
+    fn emits_events_by_default() {
+        let clock = ManualClock::new();
+        let capture = Sink::buffer();
+        let subscriber = tracing_subscriber::registry()
+            .with(TimingLayer::with_clock(clock.clone()))
+            .with(Capture(capture.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("request").entered();
+            clock.advance(Duration::from_secs(1));
+        });
+
+        assert_eq!(
+            capture.contents().unwrap(),
+            "execution_time: request: busy 1.000 seconds, idle 0.0 seconds busy_ns=1000000000 idle_ns=0\n"
+        );
+    }
+
+    #[test]
+    fn traced_timer() {
+        let sink = Sink::buffer();
+        let capture = Sink::buffer();
+        let subscriber = tracing_subscriber::registry()
+            .with(TimingLayer::new().with_sink(sink.clone()))
+            .with(Capture(capture.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut timer = ExecutionTime::traced("work");
+            assert_eq!(timer.span().metadata().unwrap().name(), SPAN_NAME);
+            timer.lap("first");
+            assert_eq!(timer.laps().len(), 1);
+        });
+
+        let recorded = capture.contents().unwrap();
+        assert_eq!(recorded.lines().count(), 1);
+        assert!(recorded.starts_with("record: elapsed="), "{recorded}");
+        assert!(recorded.ends_with(")\n"), "{recorded}");
+
+        let summary = sink.contents().unwrap();
+        assert!(summary.starts_with("work: busy "), "{summary}");
+    }
+}
@@ -1,34 +1,76 @@
-use crate::{RoundFloat, Time};
+use crate::{
+    iso8601::{format_iso8601, parse_iso8601},
+    ExactTime, ParseTimeError, Time,
+};
 use std::time::Duration;
 
-// Constants for seconds in a day, an hour, and a minute to improve readability and performance.
-const SECONDS_IN_DAY: f64 = 86400.0;
-const SECONDS_IN_HOUR: f64 = 3600.0;
-const SECONDS_IN_MINUTE: f64 = 60.0;
-
 /// Trait to extend the `Duration` type with a method to convert it to a `Time` struct.
+///
+/// Only [`DurationExtension::get_time`] is required; the other methods have
+/// default implementations built on it, so that implementors outside this
+/// crate keep compiling when methods are added.
 pub trait DurationExtension {
     /// Converts a `Duration` into a `Time` struct.
     fn get_time(&self) -> Time;
+
+    /// Converts a `Duration` into a lossless `ExactTime` struct.
+    ///
+    /// The default implementation goes through [`DurationExtension::get_time`],
+    /// whose `f64` seconds may not be exact.
+    fn get_exact_time(&self) -> ExactTime {
+        self.get_time().to_exact()
+    }
+
+    /// Formats a `Duration` as an ISO 8601 duration, such as "P1DT2H5M28.03S".
+    fn to_iso8601(&self) -> String {
+        format_iso8601(&self.get_exact_time())
+    }
+
+    /// Parses an ISO 8601 duration, such as "P1DT2H5M28.03S", into a `Duration`.
+    ///
+    /// See [`Time::from_iso8601`].
+    fn from_iso8601(text: &str) -> Result<Self, ParseTimeError>
+    where
+        Self: Sized + From<Duration>,
+    {
+        parse_iso8601(text).map(Self::from)
+    }
 }
 
 impl DurationExtension for Duration {
     fn get_time(&self) -> Time {
-        let all_seconds: f64 = self.as_secs_f64();
+        self.get_exact_time().to_time()
+    }
+
+    fn get_exact_time(&self) -> ExactTime {
+        ExactTime::from(*self)
+    }
+}
 
-        let remaining_day = all_seconds % SECONDS_IN_DAY;
-        let remaining_hour = remaining_day % SECONDS_IN_HOUR;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let days = (all_seconds / SECONDS_IN_DAY).floor() as u64;
-        let hours = (remaining_day / SECONDS_IN_HOUR).floor() as u8;
-        let minutes = (remaining_hour / SECONDS_IN_MINUTE).floor() as u8;
-        let seconds = (remaining_hour % SECONDS_IN_MINUTE).round_float(9);
+    /// An implementor that only provides the required method.
+    struct Seconds(u64);
 
-        Time {
-            days,
-            hours,
-            minutes,
-            seconds,
+    impl DurationExtension for Seconds {
+        fn get_time(&self) -> Time {
+            Duration::from_secs(self.0).get_time()
         }
     }
+
+    #[test]
+    fn default_methods() {
+        let seconds = Seconds(93_928);
+        assert_eq!(
+            seconds.get_exact_time(),
+            Duration::from_secs(93_928).get_exact_time()
+        );
+        assert_eq!(seconds.to_iso8601(), "P1DT2H5M28S");
+        assert_eq!(
+            Duration::from_iso8601("PT1M5S"),
+            Ok(Duration::from_secs(65))
+        );
+    }
 }
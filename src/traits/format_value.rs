@@ -1,9 +1,12 @@
-use crate::{SingularPlural, Unit};
+use crate::{PluralRule, SingularPlural, Unit};
 
 /// Trait for formatting floating-point values with their units
 pub trait FormatFloatValue {
     /// Formats a value with its unit (singular or plural).
     ///
+    /// The unit word follows the English plural rule applied to the displayed
+    /// number: "1 second", but "0.57 seconds" and "1.0 seconds".
+    ///
     /// ### Arguments
     ///
     /// * `decimal` - The number of decimal places to display.
@@ -17,12 +20,9 @@ pub trait FormatFloatValue {
 
 impl FormatFloatValue for f64 {
     fn format_float_unit(&self, decimal: usize, unit: Unit) -> String {
-        let unit = if *self >= 2.0 {
-            unit.plural()
-        } else {
-            unit.singular()
-        };
-        format!("{self:.decimal$} {unit}")
+        let number: String = format!("{self:.decimal$}");
+        let unit = unit.for_category(PluralRule::English.category(&number));
+        format!("{number} {unit}")
     }
 }
 
@@ -30,6 +30,8 @@ impl FormatFloatValue for f64 {
 pub trait FormatIntegerValue {
     /// Formats a value with its unit (singular or plural).
     ///
+    /// The unit word follows the English plural rule: "1 day", but "0 days".
+    ///
     /// # Arguments
     ///
     /// * `unit` - Unit types with their singular/plural pairs.
@@ -42,16 +44,12 @@ pub trait FormatIntegerValue {
 
 impl<T> FormatIntegerValue for T
 where
-    T: std::fmt::Display + PartialOrd + From<u8>,
+    T: std::fmt::Display,
 {
     fn format_unit(&self, unit: Unit) -> String {
-        // T::from(2u8) or 2.into()
-        let unit = if *self >= T::from(2u8) {
-            unit.plural()
-        } else {
-            unit.singular()
-        };
-        format!("{self} {unit}")
+        let number: String = self.to_string();
+        let unit = unit.for_category(PluralRule::English.category(&number));
+        format!("{number} {unit}")
     }
 }
 
@@ -61,7 +59,7 @@ mod tests {
 
     #[test]
     fn test_integer_formatting() {
-        assert_eq!(0u64.format_unit(Unit::Day), "0 day");
+        assert_eq!(0u64.format_unit(Unit::Day), "0 days");
         assert_eq!(1u64.format_unit(Unit::Day), "1 day");
         assert_eq!(2u64.format_unit(Unit::Day), "2 days");
     }
@@ -69,8 +67,10 @@ mod tests {
     #[test]
     fn test_floating_point_formatting() {
         assert_eq!(2.0f64.format_float_unit(0, Unit::Hour), "2 hours");
-        assert_eq!(0.567f64.format_float_unit(2, Unit::Second), "0.57 second");
-        assert_eq!(1.567f64.format_float_unit(2, Unit::Second), "1.57 second");
+        assert_eq!(0.567f64.format_float_unit(2, Unit::Second), "0.57 seconds");
+        assert_eq!(1.567f64.format_float_unit(2, Unit::Second), "1.57 seconds");
         assert_eq!(2.567f64.format_float_unit(2, Unit::Second), "2.57 seconds");
+        assert_eq!(1.0f64.format_float_unit(0, Unit::Second), "1 second");
+        assert_eq!(1.0f64.format_float_unit(1, Unit::Second), "1.0 seconds");
     }
 }
@@ -5,5 +5,5 @@ pub mod singular_plural;
 
 pub use duration_extension::DurationExtension;
 pub use format_value::{FormatFloatValue, FormatIntegerValue};
-pub use round_float::RoundFloat;
+pub use round_float::{RoundFloat, RoundingMode};
 pub use singular_plural::{SingularPlural, Unit};
@@ -1,6 +1,26 @@
+/// The ways of rounding a number to a given precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, with ties away from zero (2.5 -> 3, -2.5 -> -3).
+    #[default]
+    HalfAwayFromZero,
+    /// Round to nearest, with ties to the even neighbour, also known as
+    /// banker's rounding (2.5 -> 2, 3.5 -> 4).
+    HalfToEven,
+    /// Truncate towards zero (2.7 -> 2, -2.7 -> -2).
+    TowardZero,
+    /// Round towards negative infinity (2.7 -> 2, -2.3 -> -3).
+    Floor,
+    /// Round towards positive infinity (2.3 -> 3, -2.7 -> -2).
+    Ceil,
+}
+
 /// Trait for rounding floating-point numbers to a specified number of decimal places.
 pub trait RoundFloat {
-    /// Rounds the floating-point number to the given number of decimal places.
+    /// Rounds the floating-point number to the given number of decimal places,
+    /// with ties away from zero.
+    ///
+    /// A negative number of decimal places rounds to tens, hundreds, and so on.
     ///
     /// ### Arguments
     ///
@@ -9,27 +29,186 @@ pub trait RoundFloat {
     /// ### Returns
     ///
     /// The rounded floating-point number.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::RoundFloat;
+    ///
+    /// assert_eq!(5.22501_f64.round_float(2), 5.23);
+    /// assert_eq!(123.456_f64.round_float(-1), 120.0);
+    /// assert_eq!(1.25_f32.round_float(1), 1.3);
+    /// ```
     fn round_float(self, decimal: i32) -> Self
     where
         // This trait is object safe and Sized is required to be object safe
         Self: std::marker::Sized;
-}
 
-impl RoundFloat for f64 {
-    fn round_float(self, decimal: i32) -> f64 {
-        if decimal <= 0 || self == 0.0 {
-            self.round()
-        } else {
-            let multiplier: f64 = 10.0_f64.powi(decimal);
-            (self * multiplier).round() / multiplier
+    /// Rounds the floating-point number to the given number of decimal places
+    /// with the given rounding mode.
+    ///
+    /// The default implementation only supports [`RoundingMode::HalfAwayFromZero`]:
+    /// it calls [`RoundFloat::round_float`] whatever the mode, so that
+    /// implementors outside this crate keep compiling. The implementations for
+    /// `f32` and `f64` support all the modes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::{RoundFloat, RoundingMode};
+    ///
+    /// assert_eq!(2.345_f64.round_float_with(2, RoundingMode::TowardZero), 2.34);
+    /// assert_eq!((-2.341_f64).round_float_with(2, RoundingMode::Floor), -2.35);
+    /// assert_eq!(0.125_f64.round_float_with(2, RoundingMode::HalfToEven), 0.12);
+    /// assert_eq!(1234.0_f64.round_float_with(-2, RoundingMode::Ceil), 1300.0);
+    /// ```
+    fn round_float_with(self, decimal: i32, mode: RoundingMode) -> Self
+    where
+        Self: std::marker::Sized,
+    {
+        let _ = mode;
+        self.round_float(decimal)
+    }
+
+    /// Rounds the floating-point number to the given number of significant
+    /// digits, with ties away from zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use execution_time::RoundFloat;
+    ///
+    /// assert_eq!(0.000080057_f64.round_significant(3), 0.0000801);
+    /// assert_eq!(123456.0_f64.round_significant(2), 120000.0);
+    /// ```
+    fn round_significant(self, digits: u32) -> Self
+    where
+        Self: std::marker::Sized + Copy + Into<f64>,
+    {
+        self.round_significant_with(digits, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the floating-point number to the given number of significant
+    /// digits (at least one) with the given rounding mode.
+    ///
+    /// The magnitude of the number is read from its `f64` value, and the
+    /// rounding is done by [`RoundFloat::round_float_with`].
+    fn round_significant_with(self, digits: u32, mode: RoundingMode) -> Self
+    where
+        Self: std::marker::Sized + Copy + Into<f64>,
+    {
+        let value: f64 = self.into();
+        if value == 0.0 || !value.is_finite() {
+            return self;
         }
+
+        // Position of the first significant digit: 0 for 1.x, -5 for 0.00008.
+        let magnitude: i32 = value.abs().log10().floor() as i32;
+        let digits: i32 = digits.max(1).min(i32::MAX as u32) as i32;
+
+        let decimal: i32 = digits.saturating_sub(1).saturating_sub(magnitude);
+        self.round_float_with(decimal, mode)
     }
 }
 
+/// Implements `RoundFloat` for the floating-point types.
+macro_rules! impl_round_float {
+    ($($float:ty),*) => {
+        $(
+            impl RoundFloat for $float {
+                fn round_float(self, decimal: i32) -> $float {
+                    self.round_float_with(decimal, RoundingMode::HalfAwayFromZero)
+                }
+
+                fn round_float_with(self, decimal: i32, mode: RoundingMode) -> $float {
+                    if self == 0.0 || !self.is_finite() {
+                        return self;
+                    }
+
+                    let factor: $float = (10.0 as $float).powi(decimal.abs());
+                    if !factor.is_finite() {
+                        // Beyond the range of the type: nothing to round, or nothing left.
+                        return if decimal > 0 { self } else { 0.0 * self };
+                    }
+
+                    let scaled: $float = if decimal >= 0 {
+                        self * factor
+                    } else {
+                        self / factor
+                    };
+                    if !scaled.is_finite() {
+                        return self;
+                    }
+
+                    // Remove the representation error of the scaling (0.29 * 100 is
+                    // 28.999999999999996), so that the directed modes do not
+                    // round an exact value down or up. Without scaling there is
+                    // no such error, and a value next to an integer is kept.
+                    let nearest: $float = scaled.round();
+                    let error: $float = (scaled - nearest).abs();
+                    let scaled: $float = if decimal != 0
+                        && error <= scaled.abs() * 4.0 * <$float>::EPSILON
+                    {
+                        nearest
+                    } else {
+                        scaled
+                    };
+
+                    let rounded: $float = match mode {
+                        RoundingMode::HalfAwayFromZero => scaled.round(),
+                        RoundingMode::HalfToEven => scaled.round_ties_even(),
+                        RoundingMode::TowardZero => scaled.trunc(),
+                        RoundingMode::Floor => scaled.floor(),
+                        RoundingMode::Ceil => scaled.ceil(),
+                    };
+
+                    if decimal >= 0 {
+                        rounded / factor
+                    } else {
+                        rounded * factor
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_round_float!(f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// An implementor that only provides the required method.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Meters(f64);
+
+    impl RoundFloat for Meters {
+        fn round_float(self, decimal: i32) -> Self {
+            Meters(self.0.round_float(decimal))
+        }
+    }
+
+    impl From<Meters> for f64 {
+        fn from(meters: Meters) -> f64 {
+            meters.0
+        }
+    }
+
+    #[test]
+    fn test_default_methods() {
+        // The default `round_float_with` rounds half away from zero whatever the mode.
+        let meters = Meters(2.345);
+        assert_eq!(
+            meters.round_float_with(2, RoundingMode::TowardZero),
+            Meters(2.35)
+        );
+
+        assert_eq!(Meters(123456.0).round_significant(2), Meters(120000.0));
+        assert_eq!(Meters(0.000080057).round_significant(3), Meters(0.0000801));
+        assert_eq!(Meters(0.0).round_significant(3), Meters(0.0));
+    }
+
     #[test]
     fn test_round_positive_decimal_places() {
         // Test rounding to 2 decimal places
@@ -66,17 +245,97 @@ mod tests {
 
     #[test]
     fn test_round_negative_decimal_places() {
-        // Test rounding with negative decimal places which should be equivalent to round()
+        // Test rounding to tens
         let num = 123.456;
-        assert_eq!(num.round_float(-1), 123.0);
+        assert_eq!(num.round_float(-1), 120.0);
+
+        // Test rounding to hundreds with a negative number
+        let num = -163.456;
+        assert_eq!(num.round_float(-2), -200.0);
 
-        // Test rounding with negative decimal places with a negative number
-        let num = -123.456;
-        assert_eq!(num.round_float(-2), -123.0);
+        // Test rounding to tens, with a tie
+        let num = 125.0;
+        assert_eq!(num.round_float(-1), 130.0);
 
-        // Test rounding with negative decimal places
+        // Test rounding to more digits than available
         let num = 123.56;
-        assert_eq!(num.round_float(-1), 124.0);
+        assert_eq!(num.round_float(-3), 0.0);
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        use RoundingMode::*;
+
+        let round = |num: f64, decimal, mode| num.round_float_with(decimal, mode);
+
+        assert_eq!(round(2.5, 0, HalfAwayFromZero), 3.0);
+        assert_eq!(round(-2.5, 0, HalfAwayFromZero), -3.0);
+        assert_eq!(round(2.5, 0, HalfToEven), 2.0);
+        assert_eq!(round(3.5, 0, HalfToEven), 4.0);
+        assert_eq!(round(-0.125, 2, HalfToEven), -0.12);
+        assert_eq!(round(2.789, 2, TowardZero), 2.78);
+        assert_eq!(round(-2.789, 2, TowardZero), -2.78);
+        assert_eq!(round(2.789, 1, Floor), 2.7);
+        assert_eq!(round(-2.711, 1, Floor), -2.8);
+        assert_eq!(round(2.711, 1, Ceil), 2.8);
+        assert_eq!(round(-2.789, 1, Ceil), -2.7);
+        assert_eq!(round(1250.0, -2, HalfToEven), 1200.0);
+        assert_eq!(round(1299.0, -2, Floor), 1200.0);
+
+        // The scaling error does not move exact values.
+        assert_eq!(round(0.29, 2, Floor), 0.29);
+        assert_eq!(round(1.1, 1, Ceil), 1.1);
+    }
+
+    #[test]
+    fn test_directed_modes_next_to_integer() {
+        use RoundingMode::*;
+
+        // One ULP away from 3: no scaling, so nothing to snap.
+        let below: f64 = 2.9999999999999996;
+        let above: f64 = 3.0000000000000004;
+        assert_eq!(below.to_bits() + 1, 3.0_f64.to_bits());
+        assert_eq!(above.to_bits() - 1, 3.0_f64.to_bits());
+
+        assert_eq!(below.round_float_with(0, Floor), 2.0);
+        assert_eq!(below.round_float_with(0, TowardZero), 2.0);
+        assert_eq!(below.round_float_with(0, Ceil), 3.0);
+        assert_eq!(above.round_float_with(0, Ceil), 4.0);
+        assert_eq!(above.round_float_with(0, Floor), 3.0);
+        assert_eq!((-above).round_float_with(0, TowardZero), -3.0);
+        assert_eq!((-above).round_float_with(0, Floor), -4.0);
+    }
+
+    #[test]
+    fn test_round_significant() {
+        assert_eq!(0.000080057_f64.round_significant(3), 0.0000801);
+        assert_eq!(15.2_f64.round_significant(2), 15.0);
+        assert_eq!(123456.0_f64.round_significant(2), 120000.0);
+        assert_eq!((-987.6_f64).round_significant(1), -1000.0);
+        assert_eq!(9.87_f64.round_significant(0), 10.0);
+        assert_eq!(
+            1.999_f64.round_significant_with(2, RoundingMode::TowardZero),
+            1.9
+        );
+        assert_eq!(0.0_f64.round_significant(3), 0.0);
+    }
+
+    #[test]
+    fn test_f32() {
+        assert_eq!(5.22501_f32.round_float(2), 5.23);
+        assert_eq!(123.456_f32.round_float(-1), 120.0);
+        assert_eq!(2.5_f32.round_float_with(0, RoundingMode::HalfToEven), 2.0);
+        assert_eq!(0.012345_f32.round_significant(2), 0.012);
+        assert!(f32::NAN.round_float(2).is_nan());
+    }
+
+    #[test]
+    fn test_non_finite_and_extreme() {
+        assert_eq!(f64::INFINITY.round_float(2), f64::INFINITY);
+        assert!(f64::NAN.round_significant(2).is_nan());
+        assert_eq!(1.5_f64.round_float(400), 1.5);
+        assert_eq!(1.5_f64.round_float(-400), 0.0);
+        assert_eq!(f64::MAX.round_float(2), f64::MAX);
     }
 
     #[test]
@@ -1,10 +1,66 @@
-/// Unit types with their singular/plural pairs
-#[derive(Debug)]
+use crate::PluralCategory;
+
+/// Unit types with their singular/plural pairs, ordered from the smallest
+/// to the largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
     Second,
     Minute,
     Hour,
     Day,
+    Week,
+    /// A year of 365 days.
+    Year,
+}
+
+impl Unit {
+    /// All the units, from the smallest to the largest.
+    pub const ALL: [Unit; 9] = [
+        Unit::Nanosecond,
+        Unit::Microsecond,
+        Unit::Millisecond,
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Year,
+    ];
+
+    /// Gets the abbreviated symbol of the unit, used by the compact format.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Nanosecond => "ns",
+            Unit::Microsecond => "µs",
+            Unit::Millisecond => "ms",
+            Unit::Second => "s",
+            Unit::Minute => "m",
+            Unit::Hour => "h",
+            Unit::Day => "d",
+            Unit::Week => "w",
+            Unit::Year => "y",
+        }
+    }
+
+    /// Gets the length of the unit in nanoseconds.
+    pub fn nanos(&self) -> u128 {
+        const SECOND: u128 = 1_000_000_000;
+
+        match self {
+            Unit::Nanosecond => 1,
+            Unit::Microsecond => 1_000,
+            Unit::Millisecond => 1_000_000,
+            Unit::Second => SECOND,
+            Unit::Minute => 60 * SECOND,
+            Unit::Hour => 3600 * SECOND,
+            Unit::Day => 86400 * SECOND,
+            Unit::Week => 7 * 86400 * SECOND,
+            Unit::Year => 365 * 86400 * SECOND,
+        }
+    }
 }
 
 /// Trait for defining singular and plural forms of words.
@@ -14,24 +70,46 @@ pub trait SingularPlural {
 
     /// Gets the plural form of the word.
     fn plural(&self) -> &str;
+
+    /// Gets the form of the word for a plural category.
+    ///
+    /// By default, `One` selects the singular form and the other categories
+    /// the plural form. Languages with more forms, such as the `Few` and
+    /// `Many` categories of Polish, can override this method.
+    fn for_category(&self, category: PluralCategory) -> &str {
+        match category {
+            PluralCategory::One => self.singular(),
+            _ => self.plural(),
+        }
+    }
 }
 
 impl SingularPlural for Unit {
     fn singular(&self) -> &str {
         match self {
+            Unit::Nanosecond => "nanosecond",
+            Unit::Microsecond => "microsecond",
+            Unit::Millisecond => "millisecond",
             Unit::Second => "second",
             Unit::Minute => "minute",
             Unit::Hour => "hour",
             Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Year => "year",
         }
     }
 
     fn plural(&self) -> &str {
         match self {
+            Unit::Nanosecond => "nanoseconds",
+            Unit::Microsecond => "microseconds",
+            Unit::Millisecond => "milliseconds",
             Unit::Second => "seconds",
             Unit::Minute => "minutes",
             Unit::Hour => "hours",
             Unit::Day => "days",
+            Unit::Week => "weeks",
+            Unit::Year => "years",
         }
     }
 }
@@ -47,6 +125,8 @@ mod tests {
         assert_eq!(Unit::Minute.singular(), "minute");
         assert_eq!(Unit::Hour.singular(), "hour");
         assert_eq!(Unit::Day.singular(), "day");
+        assert_eq!(Unit::Millisecond.singular(), "millisecond");
+        assert_eq!(Unit::Week.singular(), "week");
     }
 
     #[test]
@@ -56,6 +136,28 @@ mod tests {
         assert_eq!(Unit::Minute.plural(), "minutes");
         assert_eq!(Unit::Hour.plural(), "hours");
         assert_eq!(Unit::Day.plural(), "days");
+        assert_eq!(Unit::Nanosecond.plural(), "nanoseconds");
+        assert_eq!(Unit::Year.plural(), "years");
+    }
+
+    #[test]
+    fn test_symbols() {
+        assert_eq!(Unit::Second.symbol(), "s");
+        assert_eq!(Unit::Minute.symbol(), "m");
+        assert_eq!(Unit::Hour.symbol(), "h");
+        assert_eq!(Unit::Day.symbol(), "d");
+        assert_eq!(Unit::Microsecond.symbol(), "µs");
+        assert_eq!(Unit::Year.symbol(), "y");
+    }
+
+    #[test]
+    fn test_lengths() {
+        // Up to the week, each unit is a whole multiple of the previous one.
+        for pair in Unit::ALL[..8].windows(2) {
+            assert_eq!(pair[1].nanos() % pair[0].nanos(), 0, "{pair:?}");
+        }
+        assert_eq!(Unit::Week.nanos(), 7 * Unit::Day.nanos());
+        assert_eq!(Unit::Year.nanos(), 365 * Unit::Day.nanos());
     }
 
     #[test]